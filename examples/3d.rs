@@ -84,6 +84,7 @@ fn setup(
         settings: SpineSettings {
             default_materials: false,
             mesh_type: SpineMeshType::Mesh3D,
+            mesh_3d_lit_alpha_cutoff: Some(0.5),
             ..Default::default()
         },
         ..Default::default()
@@ -154,7 +155,7 @@ impl SpineMaterial3d for Spine3DMaterial {
         let spine_settings = params
             .spine_settings_query
             .get(entity)
-            .copied()
+            .cloned()
             .unwrap_or(SpineSettings::default());
         if spine_settings.mesh_type == SpineMeshType::Mesh3D {
             let mut material = material.unwrap_or_else(|| Self::Material {
@@ -162,8 +163,13 @@ impl SpineMaterial3d for Spine3DMaterial {
             });
             material.base_color = Color::srgba(1.0, 1.0, 1.0, 1.0);
             material.base_color_texture = Some(renderable_data.texture);
-            material.alpha_mode = AlphaMode::Blend;
-            material.unlit = true;
+            if let Some(alpha_cutoff) = spine_settings.mesh_3d_lit_alpha_cutoff {
+                material.alpha_mode = AlphaMode::Mask(alpha_cutoff);
+                material.unlit = false;
+            } else {
+                material.alpha_mode = AlphaMode::Blend;
+                material.unlit = true;
+            }
             Some(material)
         } else {
             None
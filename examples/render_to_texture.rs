@@ -0,0 +1,103 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::ClearColorConfig,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+    },
+};
+use bevy_spine::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, SpinePlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, on_spawn.in_set(SpineSet::OnReady))
+        .run();
+}
+
+fn setup(
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    mut skeletons: ResMut<Assets<SkeletonData>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    commands.spawn(Camera2d);
+
+    // the texture the portrait will be rendered into
+    let size = UVec2::new(256, 256);
+    let mut portrait_image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        default(),
+    );
+    portrait_image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    let portrait_image = images.add(portrait_image);
+
+    let skeleton = SkeletonData::new_from_json(
+        asset_server.load("spineboy/export/spineboy-pro.json"),
+        asset_server.load("spineboy/export/spineboy-pma.atlas"),
+    );
+    let skeleton_handle = skeletons.add(skeleton);
+
+    // the main skeleton, rendered into the world as usual
+    commands.spawn(SpineBundle {
+        skeleton: skeleton_handle.clone().into(),
+        transform: Transform::from_xyz(-150., -200., 0.).with_scale(Vec3::ONE * 0.5),
+        ..Default::default()
+    });
+
+    // a second skeleton, rendered offscreen into `portrait_image` for display as a UI portrait
+    commands.spawn((
+        SpineBundle {
+            skeleton: skeleton_handle.into(),
+            transform: Transform::from_xyz(0., -120., 0.).with_scale(Vec3::ONE * 0.5),
+            ..Default::default()
+        },
+        SpineRenderTarget {
+            image: portrait_image.clone(),
+            size,
+            clear_color: ClearColorConfig::Custom(Color::NONE),
+        },
+    ));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::End,
+            align_items: AlignItems::Start,
+            padding: UiRect::all(Val::Px(16.)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                ImageNode::new(portrait_image),
+                Node {
+                    width: Val::Px(128.),
+                    height: Val::Px(128.),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn on_spawn(
+    mut spine_ready_event: EventReader<SpineReadyEvent>,
+    mut spine_query: Query<&mut Spine>,
+) {
+    for event in spine_ready_event.read() {
+        if let Ok(mut spine) = spine_query.get_mut(event.entity) {
+            let Spine(SkeletonController {
+                animation_state, ..
+            }) = spine.as_mut();
+            let _ = animation_state.set_animation_by_name(0, "portal", true);
+        }
+    }
+}
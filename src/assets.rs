@@ -6,8 +6,11 @@ use bevy::{
     reflect::TypePath,
 };
 use rusty_spine::SpineError;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::{crossfades::Crossfades, textures::SpineTexture};
+
 #[derive(Debug, Error)]
 pub enum SpineLoaderError {
     #[error("Could load file: {0}")]
@@ -19,9 +22,39 @@ pub enum SpineLoaderError {
 /// Bevy asset for [`rusty_spine::Atlas`], loaded from `.atlas` files.
 ///
 /// For loading a complete skeleton, see [`SkeletonData`].
-#[derive(Asset, Debug, TypePath)]
+#[derive(Asset, Debug, Clone, TypePath)]
 pub struct Atlas {
     pub atlas: Arc<rusty_spine::Atlas>,
+    /// Handles to each atlas page's image, in the same order as [`rusty_spine::Atlas::pages`].
+    ///
+    /// Loaded as real dependencies of this asset (see [`AtlasLoader`]) so that editing a page
+    /// image on disk hot-reloads it. [`SpineTextures::update`](`crate::textures::SpineTextures`)
+    /// binds [`SpineTextureCreateEvent`](`crate::textures::SpineTextureCreateEvent`) to these same
+    /// handles rather than loading the page path again, so the rendering/material systems that
+    /// consume that event's handle see the re-upload too.
+    pub page_textures: Vec<Handle<Image>>,
+}
+
+/// [`AssetLoader::Settings`] for [`SkeletonJsonLoader`] and [`SkeletonBinaryLoader`], read by
+/// [`spine_load`](`crate::spine_load`) when turning the loaded bytes into
+/// [`rusty_spine::SkeletonData`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SkeletonDataSettings {
+    /// Forwarded to `spSkeletonJson`/`spSkeletonBinary`'s `scale` before reading the skeleton, to
+    /// import at a different scale than it was authored at. Defaults to `1.0`.
+    pub scale: f32,
+    /// Overrides the premultiplied-alpha flag otherwise auto-detected from the atlas's pages.
+    /// Defaults to `None` (auto-detect).
+    pub premultiplied_alpha: Option<bool>,
+}
+
+impl Default for SkeletonDataSettings {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            premultiplied_alpha: None,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -40,14 +73,28 @@ impl AssetLoader for AtlasLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
+        let atlas = rusty_spine::Atlas::new(
+            &bytes,
+            load_context
+                .path()
+                .parent()
+                .unwrap_or_else(|| Path::new("")),
+        )?;
+        // `rusty_spine::Atlas::new` already resolved and stashed each page's image path via the
+        // create-texture callback (see `SpineTextures::init`); load them here too so Bevy tracks
+        // them as real dependencies of this asset and hot-reloads apply.
+        let page_textures = atlas
+            .pages()
+            .map(|page| {
+                let path = unsafe { page.renderer_object().get_unchecked::<SpineTexture>() }
+                    .0
+                    .clone();
+                load_context.load(path)
+            })
+            .collect();
         Ok(Atlas {
-            atlas: Arc::new(rusty_spine::Atlas::new(
-                &bytes,
-                load_context
-                    .path()
-                    .parent()
-                    .unwrap_or_else(|| Path::new("")),
-            )?),
+            atlas: Arc::new(atlas),
+            page_textures,
         })
     }
 
@@ -59,9 +106,10 @@ impl AssetLoader for AtlasLoader {
 /// Bevy asset for [`rusty_spine::SkeletonJson`], loaded from `.json` files.
 ///
 /// For loading a complete skeleton, see [`SkeletonData`].
-#[derive(Asset, Debug, TypePath)]
+#[derive(Asset, Debug, Clone, TypePath)]
 pub struct SkeletonJson {
     pub json: Vec<u8>,
+    pub settings: SkeletonDataSettings,
 }
 
 #[derive(Default)]
@@ -69,19 +117,20 @@ pub(crate) struct SkeletonJsonLoader;
 
 impl AssetLoader for SkeletonJsonLoader {
     type Asset = SkeletonJson;
-    type Settings = ();
+    type Settings = SkeletonDataSettings;
     type Error = SpineLoaderError;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         _load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         Ok(SkeletonJson {
             json: bytes.to_vec(),
+            settings: *settings,
         })
     }
 
@@ -93,9 +142,10 @@ impl AssetLoader for SkeletonJsonLoader {
 /// Bevy asset for [`rusty_spine::SkeletonBinary`], loaded from `.skel` files.
 ///
 /// For loading a complete skeleton, see [`SkeletonData`].
-#[derive(Asset, Debug, TypePath)]
+#[derive(Asset, Debug, Clone, TypePath)]
 pub struct SkeletonBinary {
     pub binary: Vec<u8>,
+    pub settings: SkeletonDataSettings,
 }
 
 #[derive(Default)]
@@ -103,19 +153,20 @@ pub(crate) struct SkeletonBinaryLoader;
 
 impl AssetLoader for SkeletonBinaryLoader {
     type Asset = SkeletonBinary;
-    type Settings = ();
+    type Settings = SkeletonDataSettings;
     type Error = SpineLoaderError;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         _load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         Ok(SkeletonBinary {
             binary: bytes.to_vec(),
+            settings: *settings,
         })
     }
 
@@ -134,6 +185,11 @@ pub struct SkeletonData {
     pub kind: SkeletonDataKind,
     pub status: SkeletonDataStatus,
     pub premultiplied_alpha: bool,
+    /// Crossfades applied to every [`AnimationStateData`](`rusty_spine::AnimationStateData`) built
+    /// from this skeleton, before any per-entity [`Crossfades`] component (which takes precedence
+    /// on conflicts). Lets every instance of a skeleton share the same default mix/crossfade
+    /// times without each [`SpineBundle`](`crate::SpineBundle`) setting them individually.
+    pub default_crossfades: Crossfades,
 }
 
 #[derive(Debug)]
@@ -184,6 +240,7 @@ impl SkeletonData {
             kind: SkeletonDataKind::JsonFile(json),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            default_crossfades: Crossfades::default(),
         }
     }
 
@@ -221,6 +278,7 @@ impl SkeletonData {
             kind: SkeletonDataKind::BinaryFile(binary),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            default_crossfades: Crossfades::default(),
         }
     }
 
@@ -235,3 +293,141 @@ impl SkeletonData {
         }
     }
 }
+
+/// RON manifest format read by [`SkeletonManifestLoader`] from `.spine`/`.skeleton.ron` files.
+#[derive(Debug, Clone, Deserialize)]
+struct SkeletonManifest {
+    atlas: String,
+    skeleton: SkeletonManifestFile,
+    #[serde(default = "SkeletonManifest::default_scale")]
+    scale: f32,
+    #[serde(default)]
+    premultiplied_alpha: Option<bool>,
+}
+
+impl SkeletonManifest {
+    fn default_scale() -> f32 {
+        1.
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SkeletonManifestFile {
+    Json(String),
+    Binary(String),
+}
+
+#[derive(Debug, Error)]
+pub enum SkeletonManifestLoaderError {
+    #[error("Could not load file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse skeleton manifest: {0}")]
+    Manifest(#[from] ron::de::SpannedError),
+    #[error("Could not load dependency: {0}")]
+    Dependency(String),
+    #[error("Spine error: {0}")]
+    Spine(#[from] SpineError),
+}
+
+/// Loads a `.spine`/`.skeleton.ron` manifest describing an atlas and a JSON or binary skeleton
+/// file (plus optional [`SkeletonDataSettings`]) into one already-[`SkeletonDataStatus::Loaded`]
+/// [`SkeletonData`], so it can be spawned directly without separately loading and stitching
+/// together an [`Atlas`] and [`SkeletonJson`]/[`SkeletonBinary`]:
+///
+/// ```ron
+/// (
+///     atlas: "skeleton.atlas",
+///     skeleton: json("skeleton.json"),
+///     scale: 1.0,
+/// )
+/// ```
+///
+/// The referenced atlas and skeleton files are loaded as true dependencies (so editing them
+/// hot-reloads this asset) and also registered as labeled sub-assets (`"atlas"`, `"skeleton"`),
+/// mirroring how Bevy's glTF loader yields one scene asset composed of several labeled sub-assets.
+#[derive(Default)]
+pub(crate) struct SkeletonManifestLoader;
+
+impl AssetLoader for SkeletonManifestLoader {
+    type Asset = SkeletonData;
+    type Settings = ();
+    type Error = SkeletonManifestLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let manifest: SkeletonManifest = ron::de::from_bytes(&bytes)?;
+        let settings = SkeletonDataSettings {
+            scale: manifest.scale,
+            premultiplied_alpha: manifest.premultiplied_alpha,
+        };
+
+        let loaded_atlas = load_context
+            .loader()
+            .immediate()
+            .load::<Atlas>(&manifest.atlas)
+            .await
+            .map_err(|err| SkeletonManifestLoaderError::Dependency(err.to_string()))?;
+        let atlas = loaded_atlas.get().atlas.clone();
+        let atlas_handle =
+            load_context.add_labeled_asset("atlas".to_owned(), loaded_atlas.get().clone());
+
+        let (kind, skeleton_data) = match &manifest.skeleton {
+            SkeletonManifestFile::Json(path) => {
+                let loaded_json = load_context
+                    .loader()
+                    .immediate()
+                    .load::<SkeletonJson>(path)
+                    .await
+                    .map_err(|err| SkeletonManifestLoaderError::Dependency(err.to_string()))?;
+                let mut skeleton_json = rusty_spine::SkeletonJson::new(atlas.clone());
+                skeleton_json.set_scale(settings.scale);
+                let skeleton_data = skeleton_json.read_skeleton_data(&loaded_json.get().json)?;
+                let json_handle = load_context
+                    .add_labeled_asset("skeleton".to_owned(), loaded_json.get().clone());
+                (SkeletonDataKind::JsonFile(json_handle), skeleton_data)
+            }
+            SkeletonManifestFile::Binary(path) => {
+                let loaded_binary = load_context
+                    .loader()
+                    .immediate()
+                    .load::<SkeletonBinary>(path)
+                    .await
+                    .map_err(|err| SkeletonManifestLoaderError::Dependency(err.to_string()))?;
+                let mut skeleton_binary = rusty_spine::SkeletonBinary::new(atlas.clone());
+                skeleton_binary.set_scale(settings.scale);
+                let skeleton_data =
+                    skeleton_binary.read_skeleton_data(&loaded_binary.get().binary)?;
+                let binary_handle = load_context
+                    .add_labeled_asset("skeleton".to_owned(), loaded_binary.get().clone());
+                (SkeletonDataKind::BinaryFile(binary_handle), skeleton_data)
+            }
+        };
+
+        let premultiplied_alpha = settings.premultiplied_alpha.unwrap_or_else(|| {
+            atlas
+                .pages()
+                .next()
+                .map(|page| page.pma())
+                .unwrap_or(false)
+        });
+
+        Ok(SkeletonData {
+            atlas_handle,
+            kind,
+            status: SkeletonDataStatus::Loaded(Arc::new(skeleton_data)),
+            premultiplied_alpha,
+            default_crossfades: Crossfades::default(),
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["spine", "skeleton.ron"]
+    }
+}
@@ -0,0 +1,105 @@
+//! An optional, opt-in subsystem that plays [`SpineEvent::Event`] audio through Bevy's audio
+//! engine.
+
+use std::collections::HashMap;
+
+use bevy::{audio::Volume, prelude::*};
+
+use crate::{SpineEvent, SpineSet};
+
+/// User-supplied audio sources, keyed by the event's `name` (not its `audio_path`).
+///
+/// Checked before `audio_path` is loaded: an event whose name is in this map plays the mapped
+/// handle and its `audio_path` is not loaded. Events with an empty `audio_path` are skipped
+/// without looking here. Useful when an event's `audio_path` doesn't resolve to a loadable asset path (e.g. it was
+/// authored relative to the Spine editor's project rather than the Bevy asset folder).
+#[derive(Resource, Default, Clone)]
+pub struct SpineAudioSources(pub HashMap<String, Handle<AudioSource>>);
+
+/// Configuration for [`SpineAudioPlugin`].
+#[derive(Resource, Clone)]
+pub struct SpineAudioSettings {
+    /// Prepended to every event's `audio_path` before loading, since Spine projects typically
+    /// author event paths relative to their own `audio` folder rather than the Bevy asset root.
+    /// Empty (the default) uses `audio_path` as-is.
+    pub base_directory: String,
+    /// World units an event's audio entity is offset along its local X axis per unit of
+    /// [`SpineEvent::Event::balance`] (default: `1.0`), nudging a [`SpatialListener`]'s panning
+    /// left/right to match the authored balance in the absence of a direct stereo-pan control in
+    /// Bevy's audio API.
+    pub balance_distance: f32,
+}
+
+impl Default for SpineAudioSettings {
+    fn default() -> Self {
+        Self {
+            base_directory: String::new(),
+            balance_distance: 1.,
+        }
+    }
+}
+
+/// Plays [`SpineEvent::Event`] audio automatically, positioned at the firing skeleton so that a
+/// [`SpatialListener`] on the camera pans and attenuates it.
+///
+/// Add alongside [`SpinePlugin`](`crate::SpinePlugin`) to turn the existing [`SpineEvent`] stream
+/// into synchronized sound without per-game glue code:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{SpinePlugin, audio::SpineAudioPlugin};
+/// # fn doc(app: &mut App) {
+/// app.add_plugins((SpinePlugin, SpineAudioPlugin));
+/// # }
+/// ```
+pub struct SpineAudioPlugin;
+
+impl Plugin for SpineAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpineAudioSources>()
+            .init_resource::<SpineAudioSettings>()
+            .add_systems(Update, spine_play_audio_events.in_set(SpineSet::OnEvent));
+    }
+}
+
+fn spine_play_audio_events(
+    mut commands: Commands,
+    mut spine_events: EventReader<SpineEvent>,
+    asset_server: Res<AssetServer>,
+    audio_sources: Res<SpineAudioSources>,
+    settings: Res<SpineAudioSettings>,
+) {
+    for event in spine_events.read() {
+        let SpineEvent::Event {
+            entity,
+            name,
+            audio_path,
+            volume,
+            balance,
+            ..
+        } = event
+        else {
+            continue;
+        };
+        if audio_path.is_empty() {
+            continue;
+        }
+        let source = if let Some(handle) = audio_sources.0.get(name) {
+            handle.clone()
+        } else {
+            asset_server.load(format!("{}{audio_path}", settings.base_directory))
+        };
+        if let Some(mut spine_entity) = commands.get_entity(*entity) {
+            spine_entity.with_children(|parent| {
+                parent.spawn((
+                    AudioPlayer(source),
+                    PlaybackSettings::DESPAWN
+                        .with_spatial(true)
+                        .with_volume(Volume::new(*volume)),
+                    Transform::from_xyz(*balance * settings.balance_distance, 0., 0.),
+                    GlobalTransform::default(),
+                ));
+            });
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! An optional bridge between [`SpineBone`] entities and a physics engine's rigid bodies.
+//!
+//! The crate does not depend on a specific physics engine (e.g. `bevy_rapier` or `bevy_xpbd`).
+//! Instead, [`BonePhysics`] just bridges [`Transform`]/[`GlobalTransform`] between a bone and
+//! whatever entity the user has wired up as its physics body; attaching the actual collider and
+//! rigid body components to that entity is left to the user.
+
+use bevy::prelude::*;
+
+use crate::{SpineBone, SpineSyncSet, SpineSystem};
+
+/// How a [`BonePhysics`] bridges a [`SpineBone`] to its physics body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BonePhysicsMode {
+    /// The physics body's transform follows the bone's animated pose each sync.
+    ///
+    /// Useful for hitboxes and colliders that should track the animation.
+    Follow,
+    /// The bone's transform is driven by the physics body's simulated pose each sync.
+    ///
+    /// Useful for ragdolls, where the bone should follow the physics simulation instead of (or in
+    /// addition to) the animation.
+    Drive,
+}
+
+/// Bridges a [`SpineBone`] entity to a physics body entity.
+///
+/// See [`BonePhysicsMode`] for the two supported directions.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BonePhysics {
+    /// The entity containing the physics body (e.g. a `bevy_rapier` `RigidBody`/`Collider`).
+    pub body: Entity,
+    pub mode: BonePhysicsMode,
+}
+
+/// Adds support for bridging [`SpineBone`] entities to physics bodies via [`BonePhysics`].
+///
+/// This plugin is not added by [`SpinePlugin`](`crate::SpinePlugin`) automatically, since it is
+/// only useful to games that pair `bevy_spine` with a physics engine.
+pub struct SpineBonePhysicsPlugin;
+
+impl Plugin for SpineBonePhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spine_bone_physics_follow
+                    .in_set(SpineSyncSet::DuringSync)
+                    .after(SpineSystem::UpdateAnimation),
+                spine_bone_physics_drive.in_set(SpineSyncSet::DuringSync),
+            ),
+        );
+    }
+}
+
+/// Writes each [`BonePhysics::Follow`](`BonePhysicsMode::Follow`) bone's animated world transform
+/// onto its physics body.
+fn spine_bone_physics_follow(
+    bone_query: Query<(&BonePhysics, &GlobalTransform), With<SpineBone>>,
+    mut body_query: Query<&mut Transform>,
+) {
+    for (physics, bone_global_transform) in bone_query.iter() {
+        if physics.mode != BonePhysicsMode::Follow {
+            continue;
+        }
+        if let Ok(mut body_transform) = body_query.get_mut(physics.body) {
+            *body_transform = bone_global_transform.compute_transform();
+        }
+    }
+}
+
+/// Writes each [`BonePhysics::Drive`](`BonePhysicsMode::Drive`) physics body's world transform,
+/// converted into the parent bone's local space, onto the bone.
+///
+/// Runs in [`SpineSyncSet::DuringSync`], before [`spine_sync_bones`](
+/// `crate::entity_sync::spine_sync_bones`) pushes [`SpineBone`] transforms onto the skeleton for
+/// this frame; writing any later (e.g. `AfterSync`) would be discarded before it ever reached the
+/// skeleton.
+fn spine_bone_physics_drive(
+    mut bone_query: Query<(&mut Transform, &BonePhysics, &SpineBone)>,
+    global_transform_query: Query<&GlobalTransform>,
+) {
+    for (mut bone_transform, physics, bone) in bone_query.iter_mut() {
+        if physics.mode != BonePhysicsMode::Drive {
+            continue;
+        }
+        let Ok(body_global_transform) = global_transform_query.get(physics.body) else {
+            continue;
+        };
+        let parent_matrix_inverse = bone
+            .parent
+            .as_ref()
+            .and_then(|parent| global_transform_query.get(parent.entity).ok())
+            .map(|parent_global_transform| parent_global_transform.compute_matrix().inverse())
+            .unwrap_or(Mat4::IDENTITY);
+        let local_matrix = parent_matrix_inverse * body_global_transform.compute_matrix();
+        let (scale, rotation, translation) = local_matrix.to_scale_rotation_translation();
+        bone_transform.translation = translation;
+        bone_transform.rotation = rotation;
+        bone_transform.scale = scale;
+    }
+}
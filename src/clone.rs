@@ -0,0 +1,99 @@
+//! A [`Command`] for deep-cloning a live [`Spine`] rig onto a new entity.
+
+use bevy::prelude::*;
+
+use crate::{
+    Crossfades, SkeletonDataHandle, Spine, SpineEventSuppressor, SpineLoader, SpineReadyEvent,
+    SpineSettings, SpineSnapshot,
+};
+
+/// Deep-clones the `source` [`Spine`] rig's pose and animation tracks onto a new entity, spawning
+/// it through the same [`SpineLoader`]/[`SpineBundle`](`crate::SpineBundle`) pipeline any other
+/// Spine entity uses.
+///
+/// `source` must already have a [`Spine`] component (i.e. have finished loading). `destination`
+/// receives a clone of `source`'s [`SkeletonDataHandle`], [`Crossfades`], and [`SpineSettings`]
+/// (if present), plus a fresh [`SpineLoader`]; once it becomes ready, its pose and animation
+/// tracks are restored from a [`SpineSnapshot`] taken from `source` at the time this command
+/// runs. Useful for spawning a duplicate at a source rig's current animation state, e.g. a clone
+/// effect or a networked "late join" snapshotting an existing skeleton onto a newly spawned one.
+///
+/// `destination` also receives the same `Transform`/`GlobalTransform`/`Visibility`/
+/// `InheritedVisibility`/`ViewVisibility` components [`SpineBundle`](`crate::SpineBundle`) gives a
+/// fresh spawn, defaulted if `destination` doesn't already have them, so it's a valid transform
+/// hierarchy root the same way any other Spine entity is (its `SpineBone`/`SpineMesh` children
+/// need a rooted `Transform` hierarchy to get their own `GlobalTransform`/`InheritedVisibility`
+/// computed). Spawn `destination` with its own `Transform` beforehand to place the clone somewhere
+/// other than the origin.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::SpineCloneCommand;
+/// # fn doc(mut commands: Commands, source: Entity) {
+/// let destination = commands.spawn(Transform::from_xyz(1., 0., 0.)).id();
+/// commands.add(SpineCloneCommand {
+///     source,
+///     destination,
+/// });
+/// # }
+/// ```
+pub struct SpineCloneCommand {
+    pub source: Entity,
+    pub destination: Entity,
+}
+
+impl Command for SpineCloneCommand {
+    fn apply(self, world: &mut World) {
+        let Some(snapshot) = world.get::<Spine>(self.source).map(Spine::snapshot) else {
+            return;
+        };
+        let skeleton = world.get::<SkeletonDataHandle>(self.source).cloned();
+        let crossfades = world.get::<Crossfades>(self.source).cloned();
+        let settings = world.get::<SpineSettings>(self.source).cloned();
+        let Some(mut destination) = world.get_entity_mut(self.destination) else {
+            return;
+        };
+        destination.insert((SpineLoader::new(), SpinePendingClone(snapshot)));
+        // Defaulted only if missing, so `destination` is a valid transform/visibility hierarchy
+        // root the same way a fresh `SpineBundle` spawn is, without clobbering a transform the
+        // caller already set on `destination` before issuing this command.
+        destination.insert_if_new((
+            Transform::default(),
+            GlobalTransform::default(),
+            Visibility::default(),
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+        ));
+        if let Some(skeleton) = skeleton {
+            destination.insert(skeleton);
+        }
+        if let Some(crossfades) = crossfades {
+            destination.insert(crossfades);
+        }
+        if let Some(settings) = settings {
+            destination.insert(settings);
+        }
+    }
+}
+
+/// Marks a [`SpineCloneCommand`]'s `destination` entity as awaiting [`spine_apply_pending_clones`]
+/// to restore its snapshot, once its [`Spine`] component exists.
+#[derive(Component)]
+struct SpinePendingClone(SpineSnapshot);
+
+/// Restores the snapshot captured by [`SpineCloneCommand`] onto its destination entity, once that
+/// entity's [`Spine`] component has finished loading.
+pub(crate) fn spine_apply_pending_clones(
+    mut spine_ready_events: EventReader<SpineReadyEvent>,
+    mut spine_query: Query<(&mut Spine, &SpinePendingClone)>,
+    spine_event_suppressor: Res<SpineEventSuppressor>,
+    mut commands: Commands,
+) {
+    for event in spine_ready_events.read() {
+        let Ok((mut spine, pending_clone)) = spine_query.get_mut(event.entity) else {
+            continue;
+        };
+        spine.restore(&pending_clone.0, &spine_event_suppressor);
+        commands.entity(event.entity).remove::<SpinePendingClone>();
+    }
+}
@@ -29,26 +29,82 @@ use rusty_spine::AnimationStateData;
 /// # }
 /// ```
 
-#[derive(Component, Default, Clone)]
+#[derive(Component, Default, Clone, Debug)]
 pub struct Crossfades {
     mix_durations: HashMap<(String, String), f32>,
+    from_mix_durations: HashMap<String, f32>,
+    to_mix_durations: HashMap<String, f32>,
+    default_mix_duration: Option<f32>,
 }
 
 impl Crossfades {
     pub fn new() -> Self {
         Self {
             mix_durations: HashMap::new(),
+            from_mix_durations: HashMap::new(),
+            to_mix_durations: HashMap::new(),
+            default_mix_duration: None,
         }
     }
 
+    /// Sets the exact mix duration to use when transitioning from `from` to `to`.
+    ///
+    /// Takes precedence over [`Crossfades::add_from`], [`Crossfades::add_to`], and
+    /// [`Crossfades::set_default_mix`].
     pub fn add(&mut self, from: &str, to: &str, mix_duration: f32) {
         self.mix_durations
             .insert((from.to_owned(), to.to_owned()), mix_duration);
     }
 
+    /// Sets the mix duration to use for every transition out of `from`, unless an exact pair is
+    /// set with [`Crossfades::add`].
+    pub fn add_from(&mut self, from: &str, mix_duration: f32) {
+        self.from_mix_durations.insert(from.to_owned(), mix_duration);
+    }
+
+    /// Sets the mix duration to use for every transition into `to`, unless an exact pair is set
+    /// with [`Crossfades::add`] or the outgoing animation has a wildcard set with
+    /// [`Crossfades::add_from`].
+    pub fn add_to(&mut self, to: &str, mix_duration: f32) {
+        self.to_mix_durations.insert(to.to_owned(), mix_duration);
+    }
+
+    /// Sets the mix duration to use for any transition not otherwise covered, mapping to
+    /// [`AnimationStateData::set_default_mix`].
+    pub fn set_default_mix(&mut self, mix_duration: f32) {
+        self.default_mix_duration = Some(mix_duration);
+    }
+
     pub(crate) fn apply(&self, animation_state_data: &mut AnimationStateData) {
-        for ((from, to), mix_duration) in self.mix_durations.iter() {
-            animation_state_data.set_mix_by_name(from, to, *mix_duration);
+        if let Some(default_mix_duration) = self.default_mix_duration {
+            animation_state_data.set_default_mix(default_mix_duration);
+        }
+        let animations: Vec<String> = animation_state_data
+            .skeleton_data()
+            .animations()
+            .map(|animation| animation.name().to_owned())
+            .collect();
+        for from in &animations {
+            for to in &animations {
+                if let Some(mix_duration) = self.resolve_mix_duration(from, to) {
+                    animation_state_data.set_mix_by_name(from, to, mix_duration);
+                }
+            }
         }
     }
+
+    /// The mix duration to use for the `from` -> `to` transition, or [`None`] if none of
+    /// [`Crossfades::add`], [`Crossfades::add_from`], or [`Crossfades::add_to`] cover it.
+    ///
+    /// Checked in that order: an exact `(from, to)` pair takes precedence over a `from` wildcard,
+    /// which takes precedence over a `to` wildcard. Doesn't consider
+    /// [`Crossfades::set_default_mix`], since that's applied once for the whole
+    /// [`AnimationStateData`] rather than per pair.
+    pub(crate) fn resolve_mix_duration(&self, from: &str, to: &str) -> Option<f32> {
+        self.mix_durations
+            .get(&(from.to_owned(), to.to_owned()))
+            .or_else(|| self.from_mix_durations.get(from))
+            .or_else(|| self.to_mix_durations.get(to))
+            .copied()
+    }
 }
@@ -0,0 +1,113 @@
+//! World-space slot/attachment geometry queries on a [`Spine`] rig.
+
+use bevy::prelude::*;
+
+use crate::{Spine, SpineBone};
+
+impl Spine {
+    /// Returns the world-space mesh vertices currently drawn by the named slot's attachment, or
+    /// [`None`] if no slot with that name exists or it has no renderable attachment this frame.
+    ///
+    /// `spine_global_transform` should be the [`GlobalTransform`] of the entity holding this
+    /// [`Spine`] component, since the renderable vertices produced by `rusty_spine` are in the
+    /// skeleton's own local space. Useful for precise hit-testing against an attachment's actual
+    /// silhouette, e.g. a melee hitbox or click target, rather than a bounding box.
+    pub fn slot_world_vertices(
+        &self,
+        spine_global_transform: &GlobalTransform,
+        slot_name: &str,
+    ) -> Option<Vec<Vec2>> {
+        let slot_index = self
+            .skeleton
+            .slots()
+            .position(|slot| slot.data().name() == slot_name)?;
+        for renderable in self.0.renderables() {
+            if renderable.slot_index == slot_index {
+                return Some(
+                    renderable
+                        .vertices
+                        .iter()
+                        .map(|vertex| {
+                            spine_global_transform
+                                .transform_point(Vec3::new(vertex[0], vertex[1], 0.))
+                                .truncate()
+                        })
+                        .collect(),
+                );
+            }
+        }
+        None
+    }
+
+    /// Returns the world-space bounding [`Rect`] of the named slot's current attachment, or
+    /// [`None`] if no slot with that name exists or it has no renderable attachment this frame.
+    ///
+    /// Folds the same vertices [`Spine::slot_world_vertices`] computes; see that method for the
+    /// meaning of `spine_global_transform`. Useful for coarse hit-testing or culling where the
+    /// exact silhouette isn't needed.
+    pub fn slot_world_aabb(
+        &self,
+        spine_global_transform: &GlobalTransform,
+        slot_name: &str,
+    ) -> Option<Rect> {
+        let vertices = self.slot_world_vertices(spine_global_transform, slot_name)?;
+        let mut vertices = vertices.into_iter();
+        let first = vertices.next()?;
+        let mut rect = Rect::new(first.x, first.y, first.x, first.y);
+        for vertex in vertices {
+            rect = rect.union_point(vertex);
+        }
+        Some(rect)
+    }
+}
+
+/// `entity`'s (a [`SpineBone`] entity) model-space matrix, relative to its [`Spine`] entity, not
+/// Bevy's global space. Composed by walking [`SpineBone::parent`] up to the skeleton root, reading
+/// straight from each ancestor's [`Transform`].
+///
+/// Unlike [`GlobalTransform`], this does not depend on Bevy's transform-propagation system having
+/// run since the current [`Transform`]s were set, so it is safe to call right after writing to a
+/// bone's [`Transform`] in the same system.
+pub fn bone_model_matrix(entity: Entity, bone_query: &Query<(&SpineBone, &Transform)>) -> Mat4 {
+    let Ok((bone, transform)) = bone_query.get(entity) else {
+        return Mat4::IDENTITY;
+    };
+    let parent_matrix = match &bone.parent {
+        Some(parent) => bone_model_matrix(parent.entity, bone_query),
+        None => Mat4::IDENTITY,
+    };
+    parent_matrix * transform.compute_matrix()
+}
+
+/// `entity`'s matrix in Bevy's global space, combining [`bone_model_matrix`] with
+/// `spine_global_transform` (the [`GlobalTransform`] of `entity`'s [`Spine`] entity).
+pub fn bone_global_matrix(
+    entity: Entity,
+    bone_query: &Query<(&SpineBone, &Transform)>,
+    spine_global_transform: &GlobalTransform,
+) -> Mat4 {
+    spine_global_transform.compute_matrix() * bone_model_matrix(entity, bone_query)
+}
+
+/// Sets `bone_transform` (`bone`'s own [`Transform`]) so that, combined with its current parent
+/// chain, `bone` reaches `world_matrix` in Bevy's global space.
+///
+/// Inverts the parent chain to derive the correct local translation/rotation/scale: computes
+/// `localMtx = inverse(parentGlobalMtx) * worldMtx`, the same relationship a standard Spine/skeletal
+/// export uses to bake a bind pose. `bone_query` is only consulted for `bone`'s ancestors, never for
+/// `bone` itself, so it is safe to pass the same query the caller is also writing `bone_transform`
+/// from (e.g. via [`Query::as_readonly`]).
+pub fn set_bone_world_transform(
+    bone: &SpineBone,
+    bone_transform: &mut Transform,
+    world_matrix: Mat4,
+    bone_query: &Query<(&SpineBone, &Transform)>,
+    spine_global_transform: &GlobalTransform,
+) {
+    let parent_model_matrix = match &bone.parent {
+        Some(parent) => bone_model_matrix(parent.entity, bone_query),
+        None => Mat4::IDENTITY,
+    };
+    let parent_global_matrix = spine_global_transform.compute_matrix() * parent_model_matrix;
+    *bone_transform = Transform::from_matrix(parent_global_matrix.inverse() * world_matrix);
+}
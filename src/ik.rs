@@ -0,0 +1,176 @@
+//! An optional FABRIK (Forward And Backward Reaching Inverse Kinematics) solver for aiming a
+//! chain of [`SpineBone`] entities at a world-space target, e.g. an arm reaching for a point or a
+//! foot planting on terrain.
+
+use bevy::prelude::*;
+
+use crate::{SpineBone, SpineSyncSet};
+
+/// Solves a chain of `length` [`SpineBone`] entities, walking up from `tip`, to aim at `target`.
+///
+/// Attach to any entity (not necessarily one of the bones in the chain); [`spine_solve_ik_chains`]
+/// only reads its fields. Only bone rotations are adjusted; translations and scale are left alone,
+/// so each bone keeps its authored length.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpineIkChain {
+    /// The last bone in the chain (the one closest to `target`).
+    pub tip: Entity,
+    /// How many bones make up the chain, counted upward from `tip` via [`SpineBone::parent`].
+    pub length: usize,
+    /// The world-space point to aim the chain's tip at.
+    pub target: Vec3,
+    /// The maximum number of backward/forward FABRIK passes to run per frame.
+    pub iterations: usize,
+}
+
+/// How close the tip must get to [`SpineIkChain::target`] before [`spine_solve_ik_chains`] stops
+/// iterating early.
+const CONVERGENCE_EPSILON: f32 = 0.01;
+
+/// Adds [`spine_solve_ik_chains`] to [`SpineSyncSet::DuringSync`], after [`spine_sync_entities`]
+/// (so it starts from the animated pose) and before `spine_sync_bones` writes bones back into the
+/// skeleton's own constraints.
+///
+/// [`spine_sync_entities`]: crate::spine_sync_entities
+pub struct SpineIkPlugin;
+
+impl Plugin for SpineIkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spine_solve_ik_chains.in_set(SpineSyncSet::DuringSync),
+        );
+    }
+}
+
+/// Solves every [`SpineIkChain`] with FABRIK, writing the result into each chain bone's
+/// [`Transform`].
+fn spine_solve_ik_chains(
+    ik_chains: Query<&SpineIkChain>,
+    mut bone_query: Query<(&SpineBone, &mut Transform)>,
+    global_transform_query: Query<&GlobalTransform>,
+) {
+    for ik_chain in ik_chains.iter() {
+        let Some(chain) = collect_chain(ik_chain.tip, ik_chain.length, &bone_query) else {
+            warn!(
+                "SpineIkChain::tip {:?} doesn't have {} SpineBone ancestors",
+                ik_chain.tip, ik_chain.length
+            );
+            continue;
+        };
+
+        let mut positions: Vec<Vec3> = chain
+            .iter()
+            .map(|entity| {
+                global_transform_query
+                    .get(*entity)
+                    .map(|global_transform| global_transform.translation())
+                    .unwrap_or(Vec3::ZERO)
+            })
+            .collect();
+        let segment_lengths: Vec<f32> = (0..positions.len() - 1)
+            .map(|i| (positions[i + 1] - positions[i]).length())
+            .collect();
+        let total_length: f32 = segment_lengths.iter().sum();
+        let root = positions[0];
+
+        solve_fabrik(
+            &mut positions,
+            &segment_lengths,
+            ik_chain.target,
+            ik_chain.iterations,
+        );
+
+        let mut parent_world_rotation = chain
+            .first()
+            .and_then(|entity| bone_query.get(*entity).ok())
+            .map(|(bone, _)| parent_rotation(bone, &global_transform_query))
+            .unwrap_or(Quat::IDENTITY);
+        for i in 0..chain.len() - 1 {
+            let direction = positions[i + 1] - positions[i];
+            let world_rotation =
+                Quat::from_axis_angle(Vec3::Z, direction.y.atan2(direction.x));
+            if let Ok((_, mut bone_transform)) = bone_query.get_mut(chain[i]) {
+                bone_transform.rotation = parent_world_rotation.inverse() * world_rotation;
+            }
+            parent_world_rotation = world_rotation;
+        }
+    }
+}
+
+/// Moves `positions` (root-first, in the same world space as [`SpineIkChain::target`]) to aim the
+/// last entry at `target`, preserving each segment's length from `segment_lengths`
+/// (`segment_lengths[i]` is the distance between `positions[i]` and `positions[i + 1]`).
+///
+/// If `target` is farther from the root than the chain's total length, the chain simply stretches
+/// straight toward it, since FABRIK can't close a gap bigger than the chain itself. Otherwise runs
+/// up to `iterations` backward/forward FABRIK passes, stopping early once the tip is within
+/// [`CONVERGENCE_EPSILON`] of `target`.
+pub(crate) fn solve_fabrik(
+    positions: &mut [Vec3],
+    segment_lengths: &[f32],
+    target: Vec3,
+    iterations: usize,
+) {
+    let root = positions[0];
+    let n = positions.len();
+
+    if (target - root).length() >= segment_lengths.iter().sum() {
+        let direction = (target - root).normalize_or_zero();
+        for i in 1..n {
+            positions[i] = positions[i - 1] + direction * segment_lengths[i - 1];
+        }
+        return;
+    }
+
+    for _ in 0..iterations {
+        // Backward pass: pull the tip to the target, then walk back to the root.
+        positions[n - 1] = target;
+        for i in (0..n - 1).rev() {
+            let direction = (positions[i] - positions[i + 1]).normalize_or_zero();
+            positions[i] = positions[i + 1] + direction * segment_lengths[i];
+        }
+        // Forward pass: pin the root back in place, then walk out to the tip.
+        positions[0] = root;
+        for i in 1..n {
+            let direction = (positions[i] - positions[i - 1]).normalize_or_zero();
+            positions[i] = positions[i - 1] + direction * segment_lengths[i - 1];
+        }
+        if (positions[n - 1] - target).length() < CONVERGENCE_EPSILON {
+            break;
+        }
+    }
+}
+
+/// The world rotation `bone`'s local [`Transform::rotation`] is relative to: its [`SpineBone`]
+/// parent if it has one, otherwise the [`Spine`](`crate::Spine`) entity it belongs to.
+fn parent_rotation(bone: &SpineBone, global_transform_query: &Query<&GlobalTransform>) -> Quat {
+    let parent_entity = bone
+        .parent
+        .as_ref()
+        .map(|parent| parent.entity)
+        .unwrap_or(bone.spine_entity);
+    global_transform_query
+        .get(parent_entity)
+        .map(|global_transform| global_transform.compute_transform().rotation)
+        .unwrap_or(Quat::IDENTITY)
+}
+
+/// Walks upward from `tip` through `length` [`SpineBone::parent`] links, returning the chain
+/// ordered root-first (so the last element is always `tip`).
+pub(crate) fn collect_chain(
+    tip: Entity,
+    length: usize,
+    bone_query: &Query<(&SpineBone, &mut Transform)>,
+) -> Option<Vec<Entity>> {
+    let mut chain = vec![tip];
+    let mut current = tip;
+    for _ in 0..length.checked_sub(1)? {
+        let (bone, _) = bone_query.get(current).ok()?;
+        let parent = bone.parent.as_ref()?.entity;
+        chain.push(parent);
+        current = parent;
+    }
+    chain.reverse();
+    Some(chain)
+}
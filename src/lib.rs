@@ -3,19 +3,24 @@
 //! Add [`SpinePlugin`] to your Bevy app and spawn a [`SpineBundle`] to get started!
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     mem::take,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use bevy::{
-    asset::load_internal_binary_asset,
+    asset::{load_internal_binary_asset, AssetId},
     image::{ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor},
     prelude::*,
     render::{
+        camera::{ClearColorConfig, RenderTarget, ScalingMode},
         mesh::{Indices, MeshVertexAttribute},
         render_asset::RenderAssetUsages,
         render_resource::{PrimitiveTopology, VertexFormat},
+        view::RenderLayers,
     },
     sprite::Material2dPlugin,
 };
@@ -32,15 +37,27 @@ use rusty_spine::{
 use textures::SpineTextureConfig;
 
 use crate::{
-    assets::{AtlasLoader, SkeletonJsonLoader},
-    materials::{SpineMaterialPlugin, DARK_COLOR_ATTRIBUTE, SHADER_HANDLE},
+    assets::{AtlasLoader, SkeletonJsonLoader, SkeletonManifestLoader},
+    materials::{SpineMaterialPlugin, DARK_COLOR_ATTRIBUTE, FUNCTIONS_SHADER_HANDLE, SHADER_HANDLE},
     rusty_spine::{
         controller::SkeletonControllerSettings, draw::CullDirection, AnimationStateData, BoneHandle,
     },
     textures::{SpineTexture, SpineTextureCreateEvent, SpineTextureDisposeEvent, SpineTextures},
 };
 
-pub use crate::{assets::*, crossfades::Crossfades, entity_sync::*, handle::*, rusty_spine::Color};
+pub use crate::{
+    assets::*,
+    bone_physics::{BonePhysics, BonePhysicsMode, SpineBonePhysicsPlugin},
+    clone::SpineCloneCommand,
+    crossfades::Crossfades,
+    entity_sync::*,
+    geometry::{bone_global_matrix, bone_model_matrix, set_bone_world_transform},
+    handle::*,
+    ik::{SpineIkChain, SpineIkPlugin},
+    ragdoll::{spine_sync_ragdoll_bones, RagdollBone, SpineRagdoll, SpineRagdollPlugin},
+    rusty_spine::Color,
+    snapshot::{SpineBoneSnapshot, SpineSlotSnapshot, SpineSnapshot, SpineTrackSnapshot},
+};
 
 /// See [`rusty_spine`] docs for more info.
 pub use crate::rusty_spine::controller::SkeletonController;
@@ -124,6 +141,8 @@ impl Plugin for SpinePlugin {
         ))
         .add_plugins(SpineSyncPlugin::first())
         .init_resource::<SpineEventQueue>()
+        .init_resource::<SpineEventSuppressor>()
+        .init_resource::<SpineRenderTargetLayers>()
         .insert_resource(SpineTextures::init())
         .insert_resource(SpineReadyEvents::default())
         .add_event::<SpineTextureCreateEvent>()
@@ -135,12 +154,14 @@ impl Plugin for SpinePlugin {
         .init_asset_loader::<AtlasLoader>()
         .init_asset_loader::<SkeletonJsonLoader>()
         .init_asset_loader::<SkeletonBinaryLoader>()
+        .init_asset_loader::<SkeletonManifestLoader>()
         .add_event::<SpineReadyEvent>()
         .add_event::<SpineEvent>()
         .add_systems(
             Update,
             (
                 spine_load.in_set(SpineSystem::Load),
+                spine_reclaim_render_target_layers.before(SpineSystem::Spawn),
                 spine_spawn
                     .in_set(SpineSystem::Spawn)
                     .after(SpineSystem::Load),
@@ -161,6 +182,12 @@ impl Plugin for SpinePlugin {
                     .in_set(SpineSystem::SpawnFlush)
                     .after(SpineSystem::Spawn)
                     .before(SpineSystem::Ready),
+                skinning::spine_build_skinned_meshes
+                    .after(SpineSystem::Ready)
+                    .before(SpineSystem::UpdateAnimation),
+                clone::spine_apply_pending_clones
+                    .after(SpineSystem::Ready)
+                    .before(SpineSet::OnReady),
             ),
         )
         .add_systems(
@@ -168,6 +195,15 @@ impl Plugin for SpinePlugin {
             adjust_spine_textures.in_set(SpineSystem::AdjustSpineTextures),
         );
 
+        load_internal_binary_asset!(
+            app,
+            FUNCTIONS_SHADER_HANDLE,
+            "spine_functions.wgsl",
+            |bytes: &[u8], path: String| Shader::from_wgsl(
+                std::str::from_utf8(bytes).unwrap().to_owned(),
+                path
+            )
+        );
         load_internal_binary_asset!(
             app,
             SHADER_HANDLE,
@@ -183,6 +219,15 @@ impl Plugin for SpinePlugin {
 #[derive(Resource, Default)]
 struct SpineEventQueue(Arc<Mutex<VecDeque<SpineEvent>>>);
 
+/// Shared flag consulted by the `AnimationState` listener installed in [`spine_spawn`]: while set,
+/// [`SpineEvent`]s are dropped instead of being queued onto [`SpineEventQueue`].
+///
+/// Used by [`Spine::restore`] to keep snapshot restoration side-effect-free, since rollback netcode
+/// resimulating past frames must not re-fire `Start`/`Interrupt`/etc. listener events that already
+/// fired the first time those frames played.
+#[derive(Resource, Default, Clone)]
+pub struct SpineEventSuppressor(pub(crate) Arc<AtomicBool>);
+
 /// A live Spine [`SkeletonController`] [`Component`], ready to be manipulated.
 ///
 /// This component does not exist on [`SpineBundle`] initially, since Spine assets may not yet be
@@ -212,6 +257,11 @@ pub struct SpineBoneParent {
     pub handle: BoneHandle,
 }
 
+/// Marker component for the entity parenting a [`Spine`]'s pool of [`SpineMesh`] children.
+///
+/// [`spine_update_meshes`] grows this pool on demand, so its child count tracks the most
+/// [`SpineMesh`]es this skeleton has ever needed in one frame, not the attachment/slot count. See
+/// [`SpineSettings::min_mesh_pool_size`] to pre-warm it.
 #[derive(Component, Clone)]
 pub struct SpineMeshes;
 
@@ -313,7 +363,7 @@ impl SpineLoader {
 /// Settings for how this Spine updates and renders.
 ///
 /// Typically set in [`SpineBundle`] when spawning an entity.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Debug, Clone)]
 pub struct SpineSettings {
     /// Indicates if default Spine materials should be used (default: `true`).
     ///
@@ -324,6 +374,62 @@ pub struct SpineSettings {
     pub mesh_type: SpineMeshType,
     /// The drawer this Spine should use to create its meshes.
     pub drawer: SpineDrawer,
+    /// Controls how often [`spine_update_animation`] advances this skeleton (default:
+    /// [`SpineUpdateMode::Always`]).
+    pub update_mode: SpineUpdateMode,
+    /// The physics stepping mode passed to [`SkeletonController::update`] each time this skeleton
+    /// is advanced (default: [`Physics::Update`]).
+    ///
+    /// Set to [`Physics::None`] to pause physics constraints (e.g. hair, cloth) for as long as this
+    /// is set, while still advancing animation time, or [`Physics::Pose`] to apply poses without
+    /// simulating physics. For a one-frame freeze instead, see [`Spine::freeze_physics`]; to move
+    /// the skeleton while physics constraints react, see [`Spine::physics_translate`].
+    pub physics: Physics,
+    /// The number of [`SpineMesh`] children to pre-warm under [`SpineMeshes`] when this skeleton
+    /// spawns (default: `0`).
+    ///
+    /// [`spine_update_meshes`] grows this pool on demand as needed each frame, reusing surplus
+    /// entities (marked [`SpineMeshState::Empty`]) rather than spawning new ones, so this only
+    /// matters for avoiding the spawn cost and one-frame pop-in the first time a skeleton needs
+    /// more meshes than it has so far, e.g. right as it becomes visible.
+    pub min_mesh_pool_size: usize,
+    /// For [`SpineMeshType::Mesh3D`], opts into lit rendering with `AlphaMode::Mask { alpha_cutoff }`
+    /// instead of the unlit `AlphaMode::Blend` a [`materials::SpineMaterial3d`] would otherwise use
+    /// (default: `None`, i.e. unlit/blend).
+    ///
+    /// Custom [`SpineMaterial3d`](`materials::SpineMaterial3d`) implementations decide for
+    /// themselves whether to honor this; it is not read by anything in this crate directly. Masking
+    /// rather than blending lets the mesh receive and cast shadows correctly under Bevy's
+    /// [`DirectionalLight`] shadow maps, since shadow maps can't represent partial alpha.
+    pub mesh_3d_lit_alpha_cutoff: Option<f32>,
+}
+
+/// Controls how often [`spine_update_animation`] advances a skeleton's animation, for scenes with
+/// many skeletons where most are off-screen or far from the camera. Set via
+/// [`SpineSettings::update_mode`].
+#[derive(Debug, Clone, Default)]
+pub enum SpineUpdateMode {
+    /// Update every frame, regardless of visibility or distance.
+    #[default]
+    Always,
+    /// Skip the [`SkeletonController::update`] call (and the time it would advance by) while
+    /// [`ViewVisibility`] is false for this entity. Event bookkeeping still runs every frame.
+    WhenVisible,
+    /// Update every frame while close to a camera, but throttle to a coarser interval the farther
+    /// this skeleton is from the nearest camera.
+    ///
+    /// `tiers` maps a squared-distance threshold (from this entity to the nearest active camera)
+    /// to an update interval in seconds, and must be sorted by ascending distance. The first tier
+    /// whose threshold is greater than or equal to the skeleton's current squared distance is
+    /// used; if none match, the skeleton updates every frame like [`SpineUpdateMode::Always`].
+    ///
+    /// Elapsed time between updates accumulates and is applied as a single larger delta once the
+    /// skeleton is updated, so animations remain temporally correct after being throttled. Moving
+    /// into a nearer tier forces an immediate catch-up update so no visible popping occurs.
+    Throttled {
+        /// `(max_distance_squared, update_interval_secs)` pairs, sorted by ascending distance.
+        tiers: Vec<(f32, f32)>,
+    },
 }
 
 /// Mesh types to use in [`SpineSettings`].
@@ -350,6 +456,22 @@ pub enum SpineDrawer {
     Combined,
     /// Do not update meshes at all.
     None,
+    /// Skin each attachment onto Bevy's GPU [`SkinnedMesh`](bevy::render::mesh::skinning::SkinnedMesh)
+    /// pipeline instead of re-solving vertices on the CPU every frame.
+    ///
+    /// The mesh is built once (see [`skinning`](`crate::skinning`)) from the skeleton's setup pose,
+    /// with weighted mesh attachments bound to their own per-vertex bone weights and every other
+    /// attachment rigidly bound to its slot's bone, and only the [`SpineBone`] entity [`Transform`]s need
+    /// updating afterwards, which [`SpineSyncSet`] already does (requires [`SpineSync`] on the
+    /// entity). Deform/FFD timelines aren't re-baked after the initial build, since that would
+    /// require re-solving vertices on the CPU same as [`SpineDrawer::Combined`]/
+    /// [`SpineDrawer::Separated`] do, defeating the point of this drawer; and clipping attachments
+    /// have no mesh to skin at all. Skeletons relying on either should use
+    /// [`SpineDrawer::Combined`] or [`SpineDrawer::Separated`] instead. Also
+    /// requires [`SpineLoader::with_children`] (the default) so bone entities exist to skin to. See
+    /// [`skinning::MAX_SKINNED_JOINTS`](`crate::skinning::MAX_SKINNED_JOINTS`) for the bone-count
+    /// ceiling this drawer supports.
+    Skinned,
 }
 
 impl Default for SpineSettings {
@@ -358,6 +480,111 @@ impl Default for SpineSettings {
             default_materials: true,
             mesh_type: SpineMeshType::Mesh2D,
             drawer: SpineDrawer::Combined,
+            update_mode: SpineUpdateMode::default(),
+            physics: Physics::Update,
+            min_mesh_pool_size: 0,
+            mesh_3d_lit_alpha_cutoff: None,
+        }
+    }
+}
+
+/// Opt-in component that renders a [`Spine`]'s meshes into an offscreen [`Image`] instead of the
+/// main world, for use as a UI portrait, paperdoll, or egui texture.
+///
+/// Insert this alongside [`SpineBundle`] (before [`SpineSystem::Spawn`] runs) on the entity that
+/// will receive the [`Spine`] component. When `spine_spawn` creates this skeleton's [`SpineMesh`]
+/// children, it will also assign them a dedicated [`RenderLayers`] layer and spawn an orthographic
+/// camera on that layer targeting `image`, so the skeleton renders only into the texture and not
+/// into the main window.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::camera::ClearColorConfig;
+/// # use bevy_spine::prelude::*;
+/// # fn doc(mut commands: Commands, mut images: ResMut<Assets<Image>>, skeleton: Handle<SkeletonData>) {
+/// let size = UVec2::new(256, 256);
+/// let mut image = Image::new_fill(
+///     bevy::render::render_resource::Extent3d {
+///         width: size.x,
+///         height: size.y,
+///         depth_or_array_layers: 1,
+///     },
+///     bevy::render::render_resource::TextureDimension::D2,
+///     &[0, 0, 0, 0],
+///     bevy::render::render_resource::TextureFormat::Bgra8UnormSrgb,
+///     RenderAssetUsages::default(),
+/// );
+/// image.texture_descriptor.usage = bevy::render::render_resource::TextureUsages::TEXTURE_BINDING
+///     | bevy::render::render_resource::TextureUsages::COPY_DST
+///     | bevy::render::render_resource::TextureUsages::RENDER_ATTACHMENT;
+/// let image = images.add(image);
+/// commands.spawn((
+///     SpineBundle {
+///         skeleton,
+///         ..Default::default()
+///     },
+///     SpineRenderTarget {
+///         image,
+///         size,
+///         clear_color: ClearColorConfig::Custom(Color::NONE),
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct SpineRenderTarget {
+    /// The image this skeleton's meshes should be rendered into.
+    pub image: Handle<Image>,
+    /// The size of `image`, in pixels, used to frame the orthographic camera.
+    pub size: UVec2,
+    /// How the render target should be cleared before drawing this skeleton.
+    pub clear_color: ClearColorConfig,
+}
+
+/// Tags a [`SpineRenderTarget`]'s render-target camera with the numeric [`RenderLayers`] index
+/// [`SpineRenderTargetLayers`] assigned it, so [`spine_reclaim_render_target_layers`] can return
+/// that index to the pool once the camera (and, with it, this component) is despawned.
+#[derive(Component)]
+struct SpineRenderTargetLayer(usize);
+
+/// Hands out numeric [`RenderLayers`] indices to [`SpineRenderTarget`] cameras in [`spine_spawn`],
+/// reusing indices freed by [`spine_reclaim_render_target_layers`] instead of growing forever.
+///
+/// Needed because menus that repeatedly spawn/despawn render targets (character previews,
+/// portraits, paperdolls) would otherwise exhaust layer indices over a long session.
+#[derive(Resource, Default)]
+struct SpineRenderTargetLayers {
+    next: usize,
+    free: Vec<usize>,
+    assigned: HashMap<Entity, usize>,
+}
+
+impl SpineRenderTargetLayers {
+    /// Reserves a layer index, preferring one freed by a despawned render target over growing
+    /// [`SpineRenderTargetLayers::next`].
+    fn alloc(&mut self) -> usize {
+        self.free.pop().unwrap_or_else(|| {
+            self.next += 1;
+            self.next
+        })
+    }
+
+    /// Records that `layer` belongs to `camera_entity`, so it can be reclaimed when that entity's
+    /// [`SpineRenderTargetLayer`] is removed (including by despawn).
+    fn assign(&mut self, camera_entity: Entity, layer: usize) {
+        self.assigned.insert(camera_entity, layer);
+    }
+}
+
+/// Returns layer indices freed by despawned (or otherwise [`SpineRenderTargetLayer`]-less) render
+/// target cameras to [`SpineRenderTargetLayers`] for reuse.
+fn spine_reclaim_render_target_layers(
+    mut removed: RemovedComponents<SpineRenderTargetLayer>,
+    mut render_target_layers: ResMut<SpineRenderTargetLayers>,
+) {
+    for entity in removed.read() {
+        if let Some(layer) = render_target_layers.assigned.remove(&entity) {
+            render_target_layers.free.push(layer);
         }
     }
 }
@@ -544,9 +771,14 @@ fn spine_load(
                 } else {
                     continue;
                 };
-                if let Some(page) = atlas.atlas.pages().next() {
-                    *premultiplied_alpha = page.pma();
-                }
+                // Auto-detected from the atlas's own pages so `*-pma.atlas` exports "just work";
+                // `SkeletonDataSettings::premultiplied_alpha` (checked below) overrides this.
+                let auto_premultiplied_alpha = atlas
+                    .atlas
+                    .pages()
+                    .next()
+                    .map(|page| page.pma())
+                    .unwrap_or(false);
                 match kind {
                     SkeletonDataKind::JsonFile(json_handle) => {
                         let json = if let Some(json) = jsons.get(json_handle) {
@@ -554,7 +786,12 @@ fn spine_load(
                         } else {
                             continue;
                         };
-                        let skeleton_json = rusty_spine::SkeletonJson::new(atlas.atlas.clone());
+                        *premultiplied_alpha = json
+                            .settings
+                            .premultiplied_alpha
+                            .unwrap_or(auto_premultiplied_alpha);
+                        let mut skeleton_json = rusty_spine::SkeletonJson::new(atlas.atlas.clone());
+                        skeleton_json.set_scale(json.settings.scale);
                         match skeleton_json.read_skeleton_data(&json.json) {
                             Ok(skeleton_data) => {
                                 *status = SkeletonDataStatus::Loaded(Arc::new(skeleton_data));
@@ -571,7 +808,13 @@ fn spine_load(
                         } else {
                             continue;
                         };
-                        let skeleton_binary = rusty_spine::SkeletonBinary::new(atlas.atlas.clone());
+                        *premultiplied_alpha = binary
+                            .settings
+                            .premultiplied_alpha
+                            .unwrap_or(auto_premultiplied_alpha);
+                        let mut skeleton_binary =
+                            rusty_spine::SkeletonBinary::new(atlas.atlas.clone());
+                        skeleton_binary.set_scale(binary.settings.scale);
                         match skeleton_binary.read_skeleton_data(&binary.binary) {
                             Ok(skeleton_data) => {
                                 *status = SkeletonDataStatus::Loaded(Arc::new(skeleton_data));
@@ -603,14 +846,20 @@ fn spine_spawn(
         Entity,
         &SkeletonDataHandle,
         Option<&Crossfades>,
+        Option<&SpineRenderTarget>,
+        Option<&SpineSettings>,
     )>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut ready_events: ResMut<SpineReadyEvents>,
     mut skeleton_data_assets: ResMut<Assets<SkeletonData>>,
     spine_event_queue: Res<SpineEventQueue>,
+    spine_event_suppressor: Res<SpineEventSuppressor>,
+    mut render_target_layers: ResMut<SpineRenderTargetLayers>,
 ) {
-    for (mut spine_loader, spine_entity, data_handle, crossfades) in skeleton_query.iter_mut() {
+    for (mut spine_loader, spine_entity, data_handle, crossfades, render_target, settings) in
+        skeleton_query.iter_mut()
+    {
         if let SpineLoader::Loading { with_children } = spine_loader.as_ref() {
             let skeleton_data_asset =
                 if let Some(skeleton_data_asset) = skeleton_data_assets.get_mut(&data_handle.0) {
@@ -621,6 +870,9 @@ fn spine_spawn(
             match &skeleton_data_asset.status {
                 SkeletonDataStatus::Loaded(skeleton_data) => {
                     let mut animation_state_data = AnimationStateData::new(skeleton_data.clone());
+                    skeleton_data_asset
+                        .default_crossfades
+                        .apply(&mut animation_state_data);
                     if let Some(crossfades) = crossfades {
                         crossfades.apply(&mut animation_state_data);
                     }
@@ -634,9 +886,12 @@ fn spine_spawn(
                             .with_premultiplied_alpha(skeleton_data_asset.premultiplied_alpha),
                     );
                     let events = spine_event_queue.0.clone();
-                    controller
-                        .animation_state
-                        .set_listener(move |_, animation_event| match animation_event {
+                    let suppressed = spine_event_suppressor.0.clone();
+                    controller.animation_state.set_listener(move |_, animation_event| {
+                        if suppressed.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        match animation_event {
                             AnimationEvent::Start { track_entry } => {
                                 let mut events = events.lock().unwrap();
                                 events.push_back(SpineEvent::Start {
@@ -693,51 +948,44 @@ fn spine_spawn(
                                     balance,
                                 });
                             }
-                        });
+                        }
+                    });
                     controller.skeleton.set_to_setup_pose();
                     let mut bones = HashMap::new();
+                    let render_target_layer = render_target.map(|_| render_target_layers.alloc());
+                    let render_layers = render_target_layer.map(RenderLayers::layer);
+                    let min_mesh_pool_size = settings
+                        .map(|settings| settings.min_mesh_pool_size)
+                        .unwrap_or_default();
                     if let Some(mut entity_commands) = commands.get_entity(spine_entity) {
                         entity_commands
                             .with_children(|parent| {
-                                // TODO: currently, a mesh is created for each slot, however when we use the
-                                // combined drawer, this many meshes is usually not necessary. instead, we
-                                // may want to dynamically create meshes as needed in the render system
-                                parent
-                                    .spawn((
-                                        Name::new("spine_meshes"),
-                                        SpineMeshes,
-                                        Transform::from_xyz(0., 0., 0.),
-                                        GlobalTransform::default(),
-                                        Visibility::default(),
-                                        InheritedVisibility::default(),
-                                        ViewVisibility::default(),
-                                    ))
-                                    .with_children(|parent| {
-                                        let mut z = 0.;
-                                        for (index, _) in controller.skeleton.slots().enumerate() {
-                                            let mut mesh = Mesh::new(
-                                                PrimitiveTopology::TriangleList,
-                                                RenderAssetUsages::MAIN_WORLD
-                                                    | RenderAssetUsages::RENDER_WORLD,
-                                            );
-                                            empty_mesh(&mut mesh);
-                                            let mesh_handle = meshes.add(mesh);
-                                            parent.spawn((
-                                                Name::new(format!("spine_mesh {}", index)),
-                                                SpineMesh {
-                                                    spine_entity,
-                                                    handle: mesh_handle.clone(),
-                                                    state: SpineMeshState::Empty,
-                                                },
-                                                Transform::from_xyz(0., 0., z),
-                                                GlobalTransform::default(),
-                                                Visibility::default(),
-                                                InheritedVisibility::default(),
-                                                ViewVisibility::default(),
-                                            ));
-                                            z += 0.001;
-                                        }
-                                    });
+                                let mut meshes_entity_commands = parent.spawn((
+                                    Name::new("spine_meshes"),
+                                    SpineMeshes,
+                                    Transform::from_xyz(0., 0., 0.),
+                                    GlobalTransform::default(),
+                                    Visibility::default(),
+                                    InheritedVisibility::default(),
+                                    ViewVisibility::default(),
+                                ));
+                                if let Some(render_layers) = &render_layers {
+                                    meshes_entity_commands.insert(render_layers.clone());
+                                }
+                                meshes_entity_commands.with_children(|parent| {
+                                    let mut z = 0.;
+                                    for index in 0..min_mesh_pool_size {
+                                        spawn_spine_mesh(
+                                            parent,
+                                            spine_entity,
+                                            &mut meshes,
+                                            render_layers.as_ref(),
+                                            index,
+                                            z,
+                                        );
+                                        z += 0.001;
+                                    }
+                                });
                                 if *with_children {
                                     spawn_bones(
                                         spine_entity,
@@ -748,6 +996,38 @@ fn spine_spawn(
                                         &mut bones,
                                     );
                                 }
+                                if let (
+                                    Some(spine_render_target),
+                                    Some(render_layers),
+                                    Some(layer),
+                                ) = (render_target, &render_layers, render_target_layer)
+                                {
+                                    let camera_entity = parent
+                                        .spawn((
+                                            Name::new("spine_render_target_camera"),
+                                            Camera2d,
+                                            Camera {
+                                                target: RenderTarget::Image(
+                                                    spine_render_target.image.clone(),
+                                                ),
+                                                clear_color: spine_render_target
+                                                    .clear_color
+                                                    .clone(),
+                                                ..default()
+                                            },
+                                            OrthographicProjection {
+                                                scaling_mode: ScalingMode::Fixed {
+                                                    width: spine_render_target.size.x as f32,
+                                                    height: spine_render_target.size.y as f32,
+                                                },
+                                                ..OrthographicProjection::default_2d()
+                                            },
+                                            render_layers.clone(),
+                                            SpineRenderTargetLayer(layer),
+                                        ))
+                                        .id();
+                                    render_target_layers.assign(camera_entity, layer);
+                                }
                             })
                             .insert(Spine(controller));
                     }
@@ -766,6 +1046,41 @@ fn spine_spawn(
     }
 }
 
+/// Spawns one pooled, initially-empty [`SpineMesh`] child of [`SpineMeshes`]. Used both to
+/// pre-warm [`SpineSettings::min_mesh_pool_size`] meshes at ready-time and to grow the pool
+/// on-demand in [`spine_update_meshes`].
+fn spawn_spine_mesh(
+    parent: &mut ChildBuilder,
+    spine_entity: Entity,
+    meshes: &mut Assets<Mesh>,
+    render_layers: Option<&RenderLayers>,
+    index: usize,
+    z: f32,
+) {
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+    );
+    empty_mesh(&mut mesh);
+    let mesh_handle = meshes.add(mesh);
+    let mut mesh_entity_commands = parent.spawn((
+        Name::new(format!("spine_mesh {}", index)),
+        SpineMesh {
+            spine_entity,
+            handle: mesh_handle,
+            state: SpineMeshState::Empty,
+        },
+        Transform::from_xyz(0., 0., z),
+        GlobalTransform::default(),
+        Visibility::default(),
+        InheritedVisibility::default(),
+        ViewVisibility::default(),
+    ));
+    if let Some(render_layers) = render_layers {
+        mesh_entity_commands.insert(render_layers.clone());
+    }
+}
+
 fn spawn_bones(
     spine_entity: Entity,
     bone_parent: Option<SpineBoneParent>,
@@ -826,15 +1141,70 @@ fn spine_ready(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn spine_update_animation(
-    mut spine_query: Query<(Entity, &mut Spine)>,
+    mut spine_query: Query<(
+        Entity,
+        &mut Spine,
+        Option<&SpineSettings>,
+        Option<&ViewVisibility>,
+        Option<&GlobalTransform>,
+    )>,
+    camera_query: Query<&GlobalTransform, With<Camera>>,
     mut spine_events: EventWriter<SpineEvent>,
     time: Res<Time>,
     spine_event_queue: Res<SpineEventQueue>,
+    mut throttle_state: Local<HashMap<Entity, (f32, Option<usize>)>>,
 ) {
-    for (_, mut spine) in spine_query.iter_mut() {
-        spine.update(time.delta_secs(), Physics::Update);
+    let mut next_throttle_state = HashMap::new();
+    for (entity, mut spine, settings, view_visibility, global_transform) in spine_query.iter_mut()
+    {
+        let physics = settings.map(|settings| settings.physics).unwrap_or(Physics::Update);
+        match settings.map(|settings| &settings.update_mode) {
+            None | Some(SpineUpdateMode::Always) => {
+                spine.update(time.delta_secs(), physics);
+            }
+            Some(SpineUpdateMode::WhenVisible) => {
+                if view_visibility.map(|view_visibility| view_visibility.get()) != Some(false) {
+                    spine.update(time.delta_secs(), physics);
+                }
+            }
+            Some(SpineUpdateMode::Throttled { tiers }) => {
+                let nearest_distance_squared = global_transform.and_then(|global_transform| {
+                    camera_query.iter().fold(None, |nearest, camera_transform| {
+                        let distance_squared = camera_transform
+                            .translation()
+                            .distance_squared(global_transform.translation());
+                        Some(nearest.map_or(distance_squared, |nearest: f32| {
+                            nearest.min(distance_squared)
+                        }))
+                    })
+                });
+                let tier_index = nearest_distance_squared.and_then(|distance_squared| {
+                    tiers
+                        .iter()
+                        .position(|(max_distance_squared, _)| {
+                            distance_squared <= *max_distance_squared
+                        })
+                });
+                let (mut elapsed, last_tier_index) =
+                    throttle_state.get(&entity).copied().unwrap_or((0., None));
+                elapsed += time.delta_secs();
+                // Crossing tiers forces a catch-up update so resuming doesn't visibly pop; a tier
+                // that's due also updates, applying the full accumulated delta at once.
+                let due = tier_index != last_tier_index
+                    || tier_index
+                        .map(|index| elapsed >= tiers[index].1)
+                        .unwrap_or(true);
+                if due {
+                    spine.update(elapsed, physics);
+                    elapsed = 0.;
+                }
+                next_throttle_state.insert(entity, (elapsed, tier_index));
+            }
+        }
     }
+    *throttle_state = next_throttle_state;
     {
         let mut events = spine_event_queue.0.lock().unwrap();
         while let Some(event) = events.pop_front() {
@@ -860,10 +1230,10 @@ fn spine_update_meshes(
         Option<&Mesh3d>,
     )>,
     mut commands: Commands,
-    meshes_query: Query<(&Parent, &Children), With<SpineMeshes>>,
+    meshes_query: Query<(Entity, &Parent, &Children, Option<&RenderLayers>), With<SpineMeshes>>,
     asset_server: Res<AssetServer>,
 ) {
-    for (meshes_parent, meshes_children) in meshes_query.iter() {
+    for (meshes_entity, meshes_parent, meshes_children, render_layers) in meshes_query.iter() {
         let Ok((mut spine, spine_mesh_type)) = spine_query.get_mut(meshes_parent.get()) else {
             continue;
         };
@@ -875,8 +1245,30 @@ fn spine_update_meshes(
                 SkeletonRenderableKind::Combined(spine.0.combined_renderables())
             }
             SpineDrawer::Separated => SkeletonRenderableKind::Simple(spine.0.renderables()),
-            SpineDrawer::None => continue,
+            SpineDrawer::None | SpineDrawer::Skinned => continue,
         };
+        let renderable_count = match &renderables {
+            SkeletonRenderableKind::Simple(vec) => vec.len(),
+            SkeletonRenderableKind::Combined(vec) => vec.len(),
+        };
+        if meshes_children.len() < renderable_count {
+            let pool_size = meshes_children.len();
+            let spine_entity = meshes_parent.get();
+            commands.entity(meshes_entity).with_children(|parent| {
+                let mut z = pool_size as f32 * 0.001;
+                for index in pool_size..renderable_count {
+                    spawn_spine_mesh(
+                        parent,
+                        spine_entity,
+                        &mut meshes,
+                        render_layers,
+                        index,
+                        z,
+                    );
+                    z += 0.001;
+                }
+            });
+        }
         let mut z = 0.;
         let mut renderable_index = 0;
         for child in meshes_children.iter() {
@@ -990,9 +1382,11 @@ fn spine_update_meshes(
                     let spine_texture =
                         unsafe { &mut *(attachment_render_object as *mut SpineTexture) };
                     let texture_path = spine_texture.0.clone();
+                    // A flat +Z normal in skeleton space is enough for Mesh3D to receive lighting;
+                    // an all-zero normal would normalize to NaN under PBR shading.
                     let mut normals = vec![];
                     for _ in 0..vertices.len() {
-                        normals.push([0., 0., 0.]);
+                        normals.push([0., 0., 1.]);
                     }
                     mesh.insert_indices(Indices::U16(indices));
                     mesh.insert_attribute(
@@ -1040,99 +1434,212 @@ fn empty_mesh(mesh: &mut Mesh) {
     mesh.insert_attribute(DARK_COLOR_ATTRIBUTE, dark_colors);
 }
 
+/// Tracks the Spine-provided sampler/alpha config for each adjusted texture, keyed by its
+/// [`AssetId`], so that it can be reapplied if the image is later hot-reloaded (see
+/// [`adjust_spine_textures`]).
 #[derive(Default)]
 struct FixSpineTextures {
-    handles: Vec<(Handle<Image>, SpineTextureConfig)>,
+    configs: HashMap<AssetId<Image>, (Handle<Image>, SpineTextureConfig)>,
+    /// Textures needing (re)application: newly created, still loading, or just hot-reloaded.
+    pending: HashSet<AssetId<Image>>,
+}
+
+/// Converts a Spine `AtlasFilter` into the texel and mip filter [`ImageSamplerDescriptor`] wants,
+/// e.g. `MipMapLinearNearest` samples texels linearly but picks the nearest mip level.
+fn convert_filter(filter: AtlasFilter) -> (ImageFilterMode, ImageFilterMode) {
+    match filter {
+        AtlasFilter::Nearest => (ImageFilterMode::Nearest, ImageFilterMode::Nearest),
+        AtlasFilter::Linear => (ImageFilterMode::Linear, ImageFilterMode::Linear),
+        AtlasFilter::MipMap => (ImageFilterMode::Linear, ImageFilterMode::Linear),
+        AtlasFilter::MipMapNearestNearest => (ImageFilterMode::Nearest, ImageFilterMode::Nearest),
+        AtlasFilter::MipMapLinearNearest => (ImageFilterMode::Linear, ImageFilterMode::Nearest),
+        AtlasFilter::MipMapNearestLinear => (ImageFilterMode::Nearest, ImageFilterMode::Linear),
+        AtlasFilter::MipMapLinearLinear => (ImageFilterMode::Linear, ImageFilterMode::Linear),
+        _ => {
+            warn!("Unsupported Spine filter: {:?}", filter);
+            (ImageFilterMode::Nearest, ImageFilterMode::Nearest)
+        }
+    }
+}
+
+fn convert_wrap(wrap: AtlasWrap) -> ImageAddressMode {
+    match wrap {
+        AtlasWrap::ClampToEdge => ImageAddressMode::ClampToEdge,
+        AtlasWrap::MirroredRepeat => ImageAddressMode::MirrorRepeat,
+        AtlasWrap::Repeat => ImageAddressMode::Repeat,
+        _ => {
+            warn!("Unsupported Spine wrap mode: {:?}", wrap);
+            ImageAddressMode::ClampToEdge
+        }
+    }
+}
+
+/// Applies a [`SpineTextureConfig`] to a freshly (re)loaded [`Image`]: the sampler's filter/wrap
+/// modes, (for premultiplied-alpha atlas pages) converting the RGB components from
+/// nonlinear-premultiplied to linear-premultiplied so they render properly in Bevy, and (for
+/// `MipMap*` filters) generating the full mip chain the atlas was exported expecting.
+fn apply_spine_texture_config(image: &mut Image, config: &SpineTextureConfig) {
+    let (min_filter, min_mipmap_filter) = convert_filter(config.min_filter);
+    let (mag_filter, _) = convert_filter(config.mag_filter);
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        min_filter,
+        mag_filter,
+        mipmap_filter: min_mipmap_filter,
+        address_mode_u: convert_wrap(config.u_wrap),
+        address_mode_v: convert_wrap(config.v_wrap),
+        ..Default::default()
+    });
+    if config.premultiplied_alpha {
+        for i in 0..(image.data.len() / 4) {
+            let mut rgba = Srgba::rgba_u8(
+                image.data[i * 4],
+                image.data[i * 4 + 1],
+                image.data[i * 4 + 2],
+                image.data[i * 4 + 3],
+            );
+            if rgba.alpha != 0. {
+                rgba = Srgba::new(
+                    rgba.red / rgba.alpha,
+                    rgba.green / rgba.alpha,
+                    rgba.blue / rgba.alpha,
+                    rgba.alpha,
+                );
+            } else {
+                rgba = Srgba::new(0., 0., 0., 0.);
+            }
+            let mut linear_rgba = LinearRgba::from(rgba);
+            linear_rgba.red *= linear_rgba.alpha;
+            linear_rgba.green *= linear_rgba.alpha;
+            linear_rgba.blue *= linear_rgba.alpha;
+            rgba = Srgba::from(linear_rgba);
+            image.data[i * 4] = (rgba.red * 255.) as u8;
+            image.data[i * 4 + 1] = (rgba.green * 255.) as u8;
+            image.data[i * 4 + 2] = (rgba.blue * 255.) as u8;
+            image.data[i * 4 + 3] = (rgba.alpha * 255.) as u8;
+        }
+    }
+    if config.generate_mipmaps {
+        generate_mipmaps(image);
+    }
+}
+
+/// Reallocates `image`'s data into a full mip chain, box-downsampling each level in linear space
+/// (so premultiplied-alpha textures, already corrected by [`apply_spine_texture_config`], mip
+/// correctly instead of darkening at the edges) and updating `mip_level_count` to match.
+///
+/// Assumes an 8-bits-per-channel RGBA format, which is all [`apply_spine_texture_config`] produces.
+fn generate_mipmaps(image: &mut Image) {
+    let mut width = image.texture_descriptor.size.width;
+    let mut height = image.texture_descriptor.size.height;
+    let mut levels = vec![take(&mut image.data)];
+    while width > 1 || height > 1 {
+        let previous = levels.last().unwrap();
+        let next_width = (width / 2).max(1);
+        let next_height = (height / 2).max(1);
+        let mut next = vec![0u8; (next_width * next_height * 4) as usize];
+        for y in 0..next_height {
+            for x in 0..next_width {
+                let mut linear = LinearRgba::new(0., 0., 0., 0.);
+                let mut samples = 0.;
+                for dy in 0..2 {
+                    for dx in 0..2 {
+                        let sample_x = (x * 2 + dx).min(width - 1);
+                        let sample_y = (y * 2 + dy).min(height - 1);
+                        let i = ((sample_y * width + sample_x) * 4) as usize;
+                        let sample = LinearRgba::from(Srgba::rgba_u8(
+                            previous[i],
+                            previous[i + 1],
+                            previous[i + 2],
+                            previous[i + 3],
+                        ));
+                        linear.red += sample.red;
+                        linear.green += sample.green;
+                        linear.blue += sample.blue;
+                        linear.alpha += sample.alpha;
+                        samples += 1.;
+                    }
+                }
+                let averaged = Srgba::from(LinearRgba::new(
+                    linear.red / samples,
+                    linear.green / samples,
+                    linear.blue / samples,
+                    linear.alpha / samples,
+                ));
+                let out = ((y * next_width + x) * 4) as usize;
+                next[out] = (averaged.red * 255.) as u8;
+                next[out + 1] = (averaged.green * 255.) as u8;
+                next[out + 2] = (averaged.blue * 255.) as u8;
+                next[out + 3] = (averaged.alpha * 255.) as u8;
+            }
+        }
+        levels.push(next);
+        width = next_width;
+        height = next_height;
+    }
+    image.texture_descriptor.mip_level_count = levels.len() as u32;
+    image.data = levels.concat();
 }
 
-/// Adjusts Spine textures to render properly.
+/// Adjusts Spine textures to render properly, including after a hot-reload replaces an atlas
+/// page's pixel data and resets its sampler to Bevy's default.
 fn adjust_spine_textures(
     mut local: Local<FixSpineTextures>,
     mut spine_texture_create_events: EventReader<SpineTextureCreateEvent>,
+    mut spine_texture_dispose_events: EventReader<SpineTextureDisposeEvent>,
+    mut image_events: EventReader<AssetEvent<Image>>,
     mut images: ResMut<Assets<Image>>,
 ) {
     for spine_texture_create_event in spine_texture_create_events.read() {
-        local.handles.push((
-            spine_texture_create_event.handle.clone(),
-            spine_texture_create_event.config,
-        ));
+        let id = spine_texture_create_event.handle.id();
+        local.configs.insert(
+            id,
+            (
+                spine_texture_create_event.handle.clone(),
+                spine_texture_create_event.config,
+            ),
+        );
+        local.pending.insert(id);
     }
-    let mut removed_handles = vec![];
-    for (handle_index, (handle, handle_config)) in local.handles.iter().enumerate() {
-        if let Some(image) = images.get_mut(handle) {
-            fn convert_filter(filter: AtlasFilter) -> ImageFilterMode {
-                match filter {
-                    AtlasFilter::Nearest => ImageFilterMode::Nearest,
-                    AtlasFilter::Linear => ImageFilterMode::Linear,
-                    _ => {
-                        warn!("Unsupported Spine filter: {:?}", filter);
-                        ImageFilterMode::Nearest
-                    }
-                }
-            }
-            fn convert_wrap(wrap: AtlasWrap) -> ImageAddressMode {
-                match wrap {
-                    AtlasWrap::ClampToEdge => ImageAddressMode::ClampToEdge,
-                    AtlasWrap::MirroredRepeat => ImageAddressMode::MirrorRepeat,
-                    AtlasWrap::Repeat => ImageAddressMode::Repeat,
-                    _ => {
-                        warn!("Unsupported Spine wrap mode: {:?}", wrap);
-                        ImageAddressMode::ClampToEdge
-                    }
-                }
-            }
-            image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
-                min_filter: convert_filter(handle_config.min_filter),
-                mag_filter: convert_filter(handle_config.mag_filter),
-                address_mode_u: convert_wrap(handle_config.u_wrap),
-                address_mode_v: convert_wrap(handle_config.v_wrap),
-                ..Default::default()
-            });
-            // The RGB components exported from Spine were premultiplied in nonlinear space, but need to be
-            // multiplied in linear space to render properly in Bevy.
-            if handle_config.premultiplied_alpha {
-                for i in 0..(image.data.len() / 4) {
-                    let mut rgba = Srgba::rgba_u8(
-                        image.data[i * 4],
-                        image.data[i * 4 + 1],
-                        image.data[i * 4 + 2],
-                        image.data[i * 4 + 3],
-                    );
-                    if rgba.alpha != 0. {
-                        rgba = Srgba::new(
-                            rgba.red / rgba.alpha,
-                            rgba.green / rgba.alpha,
-                            rgba.blue / rgba.alpha,
-                            rgba.alpha,
-                        );
-                    } else {
-                        rgba = Srgba::new(0., 0., 0., 0.);
-                    }
-                    let mut linear_rgba = LinearRgba::from(rgba);
-                    linear_rgba.red *= linear_rgba.alpha;
-                    linear_rgba.green *= linear_rgba.alpha;
-                    linear_rgba.blue *= linear_rgba.alpha;
-                    rgba = Srgba::from(linear_rgba);
-                    image.data[i * 4] = (rgba.red * 255.) as u8;
-                    image.data[i * 4 + 1] = (rgba.green * 255.) as u8;
-                    image.data[i * 4 + 2] = (rgba.blue * 255.) as u8;
-                    image.data[i * 4 + 3] = (rgba.alpha * 255.) as u8;
-                }
+    for spine_texture_dispose_event in spine_texture_dispose_events.read() {
+        let id = spine_texture_dispose_event.handle.id();
+        local.configs.remove(&id);
+        local.pending.remove(&id);
+    }
+    for image_event in image_events.read() {
+        if let AssetEvent::Modified { id } = image_event {
+            if local.configs.contains_key(id) {
+                local.pending.insert(*id);
             }
-            removed_handles.push(handle_index);
         }
     }
-    for removed_handle in removed_handles.into_iter().rev() {
-        local.handles.remove(removed_handle);
-    }
+    local.pending.retain(|id| {
+        let Some((handle, config)) = local.configs.get(id) else {
+            return false;
+        };
+        let Some(image) = images.get_mut(handle) else {
+            // still loading, try again next frame
+            return true;
+        };
+        apply_spine_texture_config(image, config);
+        false
+    });
 }
 
 mod assets;
+mod bone_physics;
+mod clone;
 mod crossfades;
 mod entity_sync;
+mod geometry;
 mod handle;
+mod ik;
+mod physics;
+mod ragdoll;
+mod snapshot;
 
+pub mod audio;
 pub mod materials;
+pub mod skinning;
 pub mod textures;
 
 #[cfg(test)]
@@ -1142,9 +1649,9 @@ mod test;
 pub mod prelude {
     pub use crate::{
         Crossfades, SkeletonController, SkeletonData, SkeletonDataHandle, Spine, SpineBone,
-        SpineBundle, SpineEvent, SpineLoader, SpineMesh, SpineMeshState, SpinePlugin,
-        SpineReadyEvent, SpineSet, SpineSettings, SpineSync, SpineSyncSet, SpineSyncSystem,
-        SpineSystem,
+        SpineBundle, SpineEvent, SpineEventSuppressor, SpineLoader, SpineMesh, SpineMeshState,
+        SpinePlugin, SpineReadyEvent, SpineRenderTarget, SpineSet, SpineSettings, SpineSnapshot,
+        SpineSync, SpineSyncSet, SpineSyncSystem, SpineSystem, SpineUpdateMode,
     };
     pub use rusty_spine::{BoneHandle, SlotHandle};
 }
@@ -2,10 +2,10 @@
 //!
 //! To create a custom material for Spine, see [`SpineMaterial`].
 
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 use bevy::{
-    asset::Asset,
+    asset::{Asset, AssetId},
     ecs::system::{StaticSystemParam, SystemParam},
     prelude::*,
     reflect::TypePath,
@@ -78,36 +78,83 @@ pub struct SpineMaterialInfo {
     pub premultiplied_alpha: bool,
 }
 
+/// Identifies [`SpineMesh`]es that can safely share a single material *asset* instance, so
+/// [`update_materials`] only calls [`Assets::add`] once per unique combination of these three
+/// fields instead of once per mesh. [`SpineMaterial::update`] is still called for every mesh, so a
+/// mesh for which it returns [`None`] (e.g. [`SpineSettings::default_materials`] is `false`) still
+/// has the material removed.
+///
+/// This does not reduce the number of draw calls: each [`SpineMesh`] is still its own
+/// `Mesh2d`/`Mesh3d` entity with its own geometry, so Bevy still issues one draw per mesh
+/// regardless of how many of them share a material handle. It only avoids redundant material
+/// assets (and the associated bind group churn) when many meshes resolve to identical material
+/// state, e.g. a crowd of skeletons sharing one atlas, blend mode, and premultiplied-alpha flag.
+///
+/// When [`SpineMaterial::update`] returns [`Some`], the mesh receives the material added for the
+/// first mesh of its batch key this frame. A custom material whose returned value varies with other,
+/// per-entity state (e.g. a custom shader parameter) therefore still shares one material instance
+/// across all meshes with the same batch key; there is no per-material opt-out.
+type SpineMaterialBatchKey = (AssetId<Image>, u8, bool);
+
+fn spine_material_batch_key(info: &SpineMaterialInfo) -> SpineMaterialBatchKey {
+    let blend_mode = match info.blend_mode {
+        BlendMode::Normal => 0,
+        BlendMode::Additive => 1,
+        BlendMode::Multiply => 2,
+        BlendMode::Screen => 3,
+    };
+    (info.texture.id(), blend_mode, info.premultiplied_alpha)
+}
+
+/// Caches, per frame, the shared material handle assigned to each [`SpineMaterialBatchKey`]. See
+/// [`update_materials`].
+struct SpineMaterialBatches<T: SpineMaterial> {
+    handles: HashMap<SpineMaterialBatchKey, Handle<T::Material>>,
+}
+
+impl<T: SpineMaterial> Default for SpineMaterialBatches<T> {
+    fn default() -> Self {
+        Self {
+            handles: HashMap::new(),
+        }
+    }
+}
+
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn update_materials<T: SpineMaterial>(
     mut commands: Commands,
     mut materials: ResMut<Assets<T::Material>>,
     mesh_query: Query<(Entity, &SpineMesh, Option<&Handle<T::Material>>)>,
     params: StaticSystemParam<T::Params<'_, '_>>,
+    mut batches: Local<SpineMaterialBatches<T>>,
 ) {
+    batches.handles.clear();
     for (mesh_entity, spine_mesh, material_handle) in mesh_query.iter() {
         let SpineMeshState::Renderable { info: data } = spine_mesh.state.clone() else {
             continue;
         };
-        if let Some((material, handle)) =
-            material_handle.and_then(|handle| materials.get_mut(handle).zip(Some(handle)))
-        {
-            if let Some(new_material) = T::update(
-                Some(material.clone()),
-                spine_mesh.spine_entity,
-                data,
-                &params,
-            ) {
-                *material = new_material;
-            } else {
-                materials.remove(handle);
+        let batch_key = spine_material_batch_key(&data);
+        let handle = T::update(None, spine_mesh.spine_entity, data, &params).map(|material| {
+            batches
+                .handles
+                .entry(batch_key)
+                .or_insert_with(|| materials.add(material))
+                .clone()
+        });
+        match (handle, material_handle) {
+            (Some(handle), Some(existing)) if *existing == handle => {}
+            (Some(handle), _) => {
+                if let Some(mut entity_commands) = commands.get_entity(mesh_entity) {
+                    entity_commands.insert(handle);
+                }
             }
-        } else if let Some(material) = T::update(None, spine_mesh.spine_entity, data, &params) {
-            let handle = materials.add(material);
-            if let Some(mut entity_commands) = commands.get_entity(mesh_entity) {
-                entity_commands.insert(handle.clone());
+            (None, Some(_)) => {
+                if let Some(mut entity_commands) = commands.get_entity(mesh_entity) {
+                    entity_commands.remove::<Handle<T::Material>>();
+                }
             }
-        };
+            (None, None) => {}
+        }
     }
 }
 
@@ -120,6 +167,33 @@ pub const DARK_COLOR_ATTRIBUTE: MeshVertexAttribute = MeshVertexAttribute::new(
 
 pub const SHADER_HANDLE: Handle<Shader> = Handle::<Shader>::weak_from_u128(10655547040990968849);
 
+/// Handle for the `bevy_spine::functions` shader import module (see `spine_functions.wgsl`).
+///
+/// Custom [`SpineMaterial`]s can reuse Spine's tinting behavior instead of reimplementing it by
+/// importing from it in their own WGSL:
+///
+/// ```wgsl
+/// #import bevy_spine::functions::spine_tint
+/// ```
+pub const FUNCTIONS_SHADER_HANDLE: Handle<Shader> =
+    Handle::<Shader>::weak_from_u128(10655547040990968850);
+
+/// Shader def enabling the dark (second) tint color in `spine.wgsl`'s vertex/fragment stages.
+///
+/// Always set by the built-in materials, since they always supply [`DARK_COLOR_ATTRIBUTE`]. A
+/// custom material built around `spine.wgsl` that omits the dark-color vertex attribute should
+/// leave this def unset, which falls back to a light-color-only tint.
+pub const DARK_COLOR_TINT_SHADER_DEF: &str = "DARK_COLOR_TINT";
+
+/// Shader def selecting the premultiplied-alpha tint formula in `spine.wgsl` (see
+/// [`SpineMaterialInfo::premultiplied_alpha`]). Unset means straight alpha.
+pub const PREMULTIPLIED_ALPHA_SHADER_DEF: &str = "PREMULTIPLIED_ALPHA";
+
+/// Shader def switching `spine.wgsl`'s vertex stage from `bevy_sprite`'s 2D mesh functions to
+/// `bevy_pbr`'s 3D ones, for custom materials rendering [`SpineMeshType::Mesh3D`](`crate::SpineMeshType::Mesh3D`)
+/// meshes that want to reuse the built-in vertex/blend logic instead of copying it.
+pub const MESH_3D_SHADER_DEF: &str = "MESH_3D";
+
 /// A [`SystemParam`] to query [`SpineSettings`].
 ///
 /// Mostly used for the built-in materials but may be useful for implementing other materials.
@@ -128,6 +202,116 @@ pub struct SpineSettingsQuery<'w, 's> {
     pub spine_settings_query: Query<'w, 's, &'static SpineSettings>,
 }
 
+/// Trait for automatically applying materials to [`SpineMeshType::Mesh3D`](`crate::SpineMeshType::Mesh3D`)
+/// [`SpineMesh`] entities.
+///
+/// Identical in shape to [`SpineMaterial`], kept as a separate trait so a 3D material (built on
+/// `bevy_pbr`'s `Material`) can't accidentally be registered with [`SpineMaterialPlugin`] (which
+/// expects a `Material2d`) or vice versa. Implement it and add it with [`SpineMaterialPlugin3d`].
+pub trait SpineMaterial3d: Sized {
+    /// The material type to apply to [`SpineMesh`]. Usually is `Self`.
+    type Material: Asset + Clone;
+    /// System parameters to query when updating this material.
+    type Params<'w, 's>: SystemParam;
+
+    /// Ran every frame for every material and every [`SpineMesh`].
+    ///
+    /// If this function returns [`Some`], then the material will be applied to the [`SpineMesh`],
+    /// otherwise it will be removed. Default materials should be removed if a custom material is
+    /// desired (see [`SpineSettings::default_materials`]).
+    fn update(
+        material: Option<Self::Material>,
+        entity: Entity,
+        renderable_data: SpineMaterialInfo,
+        params: &StaticSystemParam<Self::Params<'_, '_>>,
+    ) -> Option<Self::Material>;
+}
+
+/// Add support for a new [`SpineMaterial3d`].
+pub struct SpineMaterialPlugin3d<T: SpineMaterial3d> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: SpineMaterial3d> Default for SpineMaterialPlugin3d<T> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: SpineMaterial3d + Send + Sync + 'static> Plugin for SpineMaterialPlugin3d<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_materials_3d::<T>
+                .in_set(SpineSystem::UpdateMaterials)
+                .after(SpineSystem::UpdateMeshes),
+        );
+    }
+}
+
+/// Same caching role as [`SpineMaterialBatches`], against [`SpineMaterial3d`] instead.
+struct SpineMaterialBatches3d<T: SpineMaterial3d> {
+    handles: HashMap<SpineMaterialBatchKey, Handle<T::Material>>,
+}
+
+impl<T: SpineMaterial3d> Default for SpineMaterialBatches3d<T> {
+    fn default() -> Self {
+        Self {
+            handles: HashMap::new(),
+        }
+    }
+}
+
+/// Same batching/diffing logic as [`update_materials`], against [`SpineMaterial3d`] instead.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn update_materials_3d<T: SpineMaterial3d>(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<T::Material>>,
+    mesh_query: Query<(Entity, &SpineMesh, Option<&Handle<T::Material>>)>,
+    params: StaticSystemParam<T::Params<'_, '_>>,
+    mut batches: Local<SpineMaterialBatches3d<T>>,
+) {
+    batches.handles.clear();
+    for (mesh_entity, spine_mesh, material_handle) in mesh_query.iter() {
+        let SpineMeshState::Renderable { info: data } = spine_mesh.state.clone() else {
+            continue;
+        };
+        let batch_key = spine_material_batch_key(&data);
+        let handle = T::update(None, spine_mesh.spine_entity, data, &params).map(|material| {
+            batches
+                .handles
+                .entry(batch_key)
+                .or_insert_with(|| materials.add(material))
+                .clone()
+        });
+        match (handle, material_handle) {
+            (Some(handle), Some(existing)) if *existing == handle => {}
+            (Some(handle), _) => {
+                if let Some(mut entity_commands) = commands.get_entity(mesh_entity) {
+                    entity_commands.insert(handle);
+                }
+            }
+            (None, Some(_)) => {
+                if let Some(mut entity_commands) = commands.get_entity(mesh_entity) {
+                    entity_commands.remove::<Handle<T::Material>>();
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// A [`SystemParam`] to query [`SpineSettings`], for use as a [`SpineMaterial3d::Params`].
+///
+/// Identical to [`SpineSettingsQuery`]; kept separate so [`SpineMaterial3d::Params`] types read as
+/// 3D-specific rather than borrowing the 2D one.
+#[derive(SystemParam)]
+pub struct Spine3dSettingsQuery<'w, 's> {
+    pub spine_settings_query: Query<'w, 's, &'static SpineSettings>,
+}
+
 macro_rules! material {
     ($(#[$($attrss:tt)*])* $name:ident, $blend_mode:expr, $premultiplied_alpha:expr, $blend_state:expr) => {
         $(#[$($attrss)*])*
@@ -153,6 +337,14 @@ macro_rules! material {
                 SHADER_HANDLE.into()
             }
 
+            fn prepass_vertex_shader() -> ShaderRef {
+                SHADER_HANDLE.into()
+            }
+
+            fn prepass_fragment_shader() -> ShaderRef {
+                SHADER_HANDLE.into()
+            }
+
             fn specialize(
                 descriptor: &mut RenderPipelineDescriptor,
                 layout: &MeshVertexBufferLayout,
@@ -167,7 +359,17 @@ macro_rules! material {
                 ];
                 let vertex_buffer_layout = layout.get_layout(&vertex_attributes)?;
                 descriptor.vertex.buffers = vec![vertex_buffer_layout];
+                descriptor
+                    .vertex
+                    .shader_defs
+                    .push(DARK_COLOR_TINT_SHADER_DEF.into());
                 if let Some(fragment) = &mut descriptor.fragment {
+                    fragment.shader_defs.push(DARK_COLOR_TINT_SHADER_DEF.into());
+                    if $premultiplied_alpha {
+                        fragment
+                            .shader_defs
+                            .push(PREMULTIPLIED_ALPHA_SHADER_DEF.into());
+                    }
                     if let Some(target_state) = &mut fragment.targets[0] {
                         target_state.blend = Some($blend_state);
                     }
@@ -187,7 +389,7 @@ macro_rules! material {
                 renderable_data: SpineMaterialInfo,
                 params: &StaticSystemParam<Self::Params<'_, '_>>,
             ) -> Option<Self> {
-                let spine_settings = params.spine_settings_query.get(entity).copied().unwrap_or(SpineSettings::default());
+                let spine_settings = params.spine_settings_query.get(entity).cloned().unwrap_or(SpineSettings::default());
                 if spine_settings.default_materials && renderable_data.blend_mode == $blend_mode && renderable_data.premultiplied_alpha == $premultiplied_alpha {
                     let mut material = material.unwrap_or_else(|| Self::default());
                     material.image = renderable_data.texture;
@@ -200,6 +402,12 @@ macro_rules! material {
     };
 }
 
+// One `Material2d` per Spine `BlendMode` (Normal/Additive/Multiply/Screen), each duplicated for
+// straight vs premultiplied-alpha textures. All eight share `spine.wgsl`/`spine_functions.wgsl`
+// and only differ in their wgpu `BlendState` and the `PREMULTIPLIED_ALPHA_SHADER_DEF`/tint-formula
+// branch it selects; the two-color (dark) tint is always enabled since every built-in material
+// supplies `DARK_COLOR_ATTRIBUTE`.
+
 material!(
     /// Normal blend mode material, non-premultiplied-alpha
     SpineNormalMaterial,
@@ -265,7 +473,7 @@ material!(
     BlendState {
         color: BlendComponent {
             src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            dst_factor: BlendFactor::OneMinusSrc,
             operation: BlendOperation::Add,
         },
         alpha: BlendComponent {
@@ -341,7 +549,7 @@ material!(
     BlendState {
         color: BlendComponent {
             src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            dst_factor: BlendFactor::OneMinusSrc,
             operation: BlendOperation::Add,
         },
         alpha: BlendComponent {
@@ -0,0 +1,41 @@
+//! Per-instance physics controls for [`Spine`], layered on top of [`SpineSettings::physics`]
+//! (which governs the stepping mode used by the plugin's own per-frame update).
+
+use bevy::prelude::*;
+use rusty_spine::Physics;
+
+use crate::Spine;
+
+impl Spine {
+    /// Resets all physics constraints (hair, cloth, etc.) to their setup pose.
+    ///
+    /// Useful after rewinding or otherwise changing animation state out from under the physics
+    /// simulation, since constraints would otherwise try to interpolate from a pose that no longer
+    /// makes sense.
+    pub fn reset_physics(&mut self) {
+        self.update(0., Physics::Reset);
+    }
+
+    /// Freezes physics constraints for this update only, so they don't react to any motion that
+    /// happens this frame.
+    ///
+    /// This is a one-shot freeze, not a persistent toggle: nothing is stored on [`Spine`] to keep
+    /// constraints disabled, so the very next automatic update (governed by
+    /// [`SpineSettings::physics`](`crate::SpineSettings::physics`), `Physics::Update` by default)
+    /// resumes stepping them normally. Call this every frame constraints should stay frozen (e.g.
+    /// while a cutscene drives this entity's [`Transform`] directly), and follow up with
+    /// [`reset_physics`](`Spine::reset_physics`) afterwards so constraints resume cleanly instead of
+    /// snapping to catch up on motion they missed while frozen.
+    pub fn freeze_physics(&mut self) {
+        self.update(0., Physics::None);
+    }
+
+    /// Translates the skeleton's root by `translation` while letting physics constraints react
+    /// naturally, rather than snapping instantly the way moving this entity's [`Transform`] would.
+    ///
+    /// Use this when teleporting a character so secondary motion (hair, cloth) lags behind
+    /// realistically instead of popping to the new position.
+    pub fn physics_translate(&mut self, translation: Vec2) {
+        self.skeleton.physics_translate(translation.x, translation.y);
+    }
+}
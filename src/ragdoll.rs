@@ -0,0 +1,67 @@
+//! An optional synchronizer that blends a [`Spine`]'s animated pose with bone transforms driven
+//! directly by an external physics solver (e.g. `bevy_rapier`/`avian`), for ragdoll-style effects.
+//!
+//! Unlike [`BonePhysics`](`crate::BonePhysics`), which bridges a bone to a *separate* physics body
+//! entity, this assumes the physics engine simulates the [`SpineBone`] entity itself (a rigid body
+//! attached directly to it) and writes its solved pose straight into the bone's [`Transform`].
+
+use bevy::prelude::*;
+
+use crate::{Spine, SpineBone, SpineSyncSet};
+
+/// Marker for a [`Spine`] entity whose [`RagdollBone`] children should be blended by
+/// [`spine_sync_ragdoll_bones`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpineRagdoll;
+
+/// Blends a [`SpineBone`] entity's animated pose with its physics-simulated pose.
+///
+/// `weight` is how much of the physics pose to keep: `0.0` is fully animated, `1.0` is fully
+/// physics-driven. Ramp this down over time to blend a limp ragdoll limb back into its animation.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RagdollBone {
+    pub weight: f32,
+}
+
+/// Adds [`spine_sync_ragdoll_bones`] to [`SpineSyncSet::DuringSync`].
+///
+/// Not added by [`SpinePlugin`](`crate::SpinePlugin`) automatically, since it is only useful to
+/// games pairing `bevy_spine` with a physics engine. Add whatever system writes the physics
+/// solver's pose onto [`RagdollBone`] entities' [`Transform`]s `.before(spine_sync_ragdoll_bones)`
+/// so the blend reads the freshly-simulated pose rather than last frame's.
+pub struct SpineRagdollPlugin;
+
+impl Plugin for SpineRagdollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spine_sync_ragdoll_bones.in_set(SpineSyncSet::DuringSync),
+        );
+    }
+}
+
+/// Lerps each [`RagdollBone`] entity's [`Transform`] (assumed to already hold the physics-solved
+/// pose for this frame) back toward the skeleton's animated pose by `1.0 - weight`, so
+/// `spine_sync_bones` writes the blended result into the Spine skeleton honoring its constraints.
+pub fn spine_sync_ragdoll_bones(
+    mut bone_query: Query<(&mut Transform, &SpineBone, &RagdollBone)>,
+    spine_query: Query<&Spine, With<SpineRagdoll>>,
+) {
+    for (mut bone_transform, bone, ragdoll_bone) in bone_query.iter_mut() {
+        let Ok(spine) = spine_query.get(bone.spine_entity) else {
+            continue;
+        };
+        let Some(animated_bone) = bone.handle.get(&spine.skeleton) else {
+            continue;
+        };
+        let weight = ragdoll_bone.weight.clamp(0., 1.);
+        let animated_translation = Vec3::new(
+            animated_bone.x(),
+            animated_bone.y(),
+            bone_transform.translation.z,
+        );
+        let animated_rotation = Quat::from_axis_angle(Vec3::Z, animated_bone.rotation().to_radians());
+        bone_transform.translation = animated_translation.lerp(bone_transform.translation, weight);
+        bone_transform.rotation = animated_rotation.slerp(bone_transform.rotation, weight);
+    }
+}
@@ -0,0 +1,270 @@
+//! GPU-skinned rendering for [`SpineDrawer::Skinned`], built on Bevy's [`SkinnedMesh`] pipeline
+//! instead of re-solving vertices on the CPU every frame.
+//!
+//! Weighted mesh attachments (capes, cloth) carry their real per-vertex bone indices/weights over
+//! from `rusty_spine`, clamped to the 4 most significant influences and renormalized (Bevy's
+//! skinning pipeline only supports 4 joints per vertex). Other attachment types (regions, rigid
+//! meshes) fall back to a single full-weight bone, the slot's own.
+
+use std::collections::HashMap;
+
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::{
+            skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+            Indices,
+        },
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
+    },
+};
+
+use crate::{Spine, SpineBone, SpineDrawer, SpineMeshType, SpineReadyEvent, SpineSettings};
+
+/// The maximum number of bones a skeleton can have to use [`SpineDrawer::Skinned`].
+///
+/// Bevy's skinning pipeline uploads one matrix per joint to a uniform buffer, which is capped at
+/// this size (particularly on WebGL2). Skeletons with more bones than this fall back to not
+/// rendering at all; use [`SpineDrawer::Combined`] or [`SpineDrawer::Separated`] instead.
+pub const MAX_SKINNED_JOINTS: usize = 256;
+
+/// Marker component for the combined mesh entity built for a [`SpineDrawer::Skinned`] skeleton.
+///
+/// Spawned as a child of the [`Spine`] entity the first time it becomes ready, and never rebuilt
+/// afterwards (see [`SpineDrawer::Skinned`] for why).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpineSkinnedMesh;
+
+/// Builds the combined [`SkinnedMesh`] for any newly-ready [`Spine`] entity using
+/// [`SpineDrawer::Skinned`]. See the module docs for the skinning model used.
+pub(crate) fn spine_build_skinned_meshes(
+    mut spine_ready_events: EventReader<SpineReadyEvent>,
+    spine_query: Query<(&Spine, Option<&SpineSettings>)>,
+    bone_query: Query<(&SpineBone, &Transform)>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut inverse_bindposes_assets: ResMut<Assets<SkinnedMeshInverseBindposes>>,
+) {
+    for event in spine_ready_events.read() {
+        let Ok((spine, settings)) = spine_query.get(event.entity) else {
+            continue;
+        };
+        let (drawer, mesh_type) = settings
+            .map(|settings| (settings.drawer, settings.mesh_type))
+            .unwrap_or((SpineDrawer::Combined, SpineMeshType::Mesh2D));
+        if drawer != SpineDrawer::Skinned {
+            continue;
+        }
+
+        let skeleton = &spine.skeleton;
+        let bone_names: Vec<String> = skeleton
+            .bones()
+            .map(|bone| bone.data().name().to_owned())
+            .collect();
+        if bone_names.len() > MAX_SKINNED_JOINTS {
+            warn!(
+                "spine entity {:?} has {} bones, over the {} joint limit for SpineDrawer::Skinned; \
+                 not building a mesh (use SpineDrawer::Combined or ::Separated instead)",
+                event.entity,
+                bone_names.len(),
+                MAX_SKINNED_JOINTS
+            );
+            continue;
+        }
+
+        let Some(joints) = bone_names
+            .iter()
+            .map(|name| event.bones.get(name).copied())
+            .collect::<Option<Vec<Entity>>>()
+        else {
+            warn!(
+                "spine entity {:?} uses SpineDrawer::Skinned but has no bone entities; use \
+                 SpineLoader::with_children (the default) instead of \
+                 SpineLoader::without_children",
+                event.entity
+            );
+            continue;
+        };
+
+        let mut bind_matrix_cache = HashMap::new();
+        let inverse_bindposes: Vec<Mat4> = joints
+            .iter()
+            .map(|joint| bone_bind_matrix(*joint, &bone_query, &mut bind_matrix_cache).inverse())
+            .collect();
+        let inverse_bindposes =
+            inverse_bindposes_assets.add(SkinnedMeshInverseBindposes::from(inverse_bindposes));
+
+        let slot_bones: Vec<Option<u16>> = skeleton
+            .slots()
+            .map(|slot| {
+                bone_names
+                    .iter()
+                    .position(|name| name == slot.bone().data().name())
+                    .map(|index| index as u16)
+            })
+            .collect();
+
+        let slots: Vec<_> = skeleton.slots().collect();
+
+        let mut positions: Vec<[f32; 3]> = vec![];
+        let mut normals: Vec<[f32; 3]> = vec![];
+        let mut uvs: Vec<[f32; 2]> = vec![];
+        let mut joint_indices: Vec<[u16; 4]> = vec![];
+        let mut joint_weights: Vec<[f32; 4]> = vec![];
+        let mut indices: Vec<u32> = vec![];
+        for renderable in spine.0.renderables() {
+            let Some(Some(bone_index)) = slot_bones.get(renderable.slot_index).copied() else {
+                continue;
+            };
+            // Weighted mesh attachments carry real per-vertex bone weights; anything else (region
+            // attachments, rigid meshes) rigidly follows the slot's own bone.
+            let vertex_weights = slots
+                .get(renderable.slot_index)
+                .and_then(|slot| slot.attachment())
+                .and_then(|attachment| attachment.as_mesh())
+                .and_then(|mesh| mesh_vertex_weights(&mesh, skeleton, &bone_names));
+            let base_vertex = positions.len() as u32;
+            for (vertex_index, vertex) in renderable.vertices.iter().enumerate() {
+                positions.push([vertex[0], vertex[1], 0.]);
+                normals.push([0., 0., 1.]);
+                let (vertex_joint_indices, vertex_joint_weights) = vertex_weights
+                    .as_ref()
+                    .and_then(|weights| weights.get(vertex_index))
+                    .copied()
+                    .unwrap_or(([bone_index, 0, 0, 0], [1., 0., 0., 0.]));
+                joint_indices.push(vertex_joint_indices);
+                joint_weights.push(vertex_joint_weights);
+            }
+            uvs.extend(renderable.uvs.iter().copied());
+            indices.extend(
+                renderable
+                    .indices
+                    .iter()
+                    .map(|index| base_vertex + *index as u32),
+            );
+        }
+
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+        );
+        mesh.insert_indices(Indices::U32(indices));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_JOINT_INDEX, joint_indices);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT, joint_weights);
+        let mesh = meshes.add(mesh);
+
+        if let Some(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.with_children(|parent| {
+                let mut skinned_mesh_entity = parent.spawn((
+                    Name::new("spine_skinned_mesh"),
+                    SpineSkinnedMesh,
+                    SkinnedMesh {
+                        inverse_bindposes,
+                        joints,
+                    },
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ));
+                match mesh_type {
+                    SpineMeshType::Mesh2D => {
+                        skinned_mesh_entity.insert(Mesh2d(mesh));
+                    }
+                    SpineMeshType::Mesh3D => {
+                        skinned_mesh_entity.insert(Mesh3d(mesh));
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Extracts per-vertex bone joint indices/weights from a weighted `rusty_spine` mesh attachment,
+/// clamped to the 4 strongest influences per vertex and renormalized to sum to 1.
+///
+/// `rusty_spine::MeshAttachment::bones` stores, per vertex, an influence count followed by that
+/// many skeleton bone indices; `vertices` stores the matching `(x, y, weight)` triples in the same
+/// order (the attachment's own local-space vertex position isn't used here, only the weight).
+/// Returns [`None`] for unweighted meshes (`bones` is empty), which rigidly follow a single bone
+/// and don't need this.
+fn mesh_vertex_weights(
+    mesh: &rusty_spine::MeshAttachment,
+    skeleton: &rusty_spine::Skeleton,
+    bone_names: &[String],
+) -> Option<Vec<([u16; 4], [f32; 4])>> {
+    let bones = mesh.bones();
+    if bones.is_empty() {
+        return None;
+    }
+    let vertices = mesh.vertices();
+    let mut per_vertex = vec![];
+    let mut bone_cursor = 0;
+    let mut vertex_cursor = 0;
+    while bone_cursor < bones.len() {
+        let influence_count = bones[bone_cursor] as usize;
+        bone_cursor += 1;
+        let mut influences: Vec<(u16, f32)> = vec![];
+        for _ in 0..influence_count {
+            let skeleton_bone_index = bones[bone_cursor] as usize;
+            bone_cursor += 1;
+            let weight = vertices[vertex_cursor + 2];
+            vertex_cursor += 3;
+            let Some(bone) = skeleton.bones().nth(skeleton_bone_index) else {
+                continue;
+            };
+            let Some(joint_index) = bone_names
+                .iter()
+                .position(|name| name == bone.data().name())
+            else {
+                continue;
+            };
+            influences.push((joint_index as u16, weight));
+        }
+        influences.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+        influences.truncate(4);
+        let total_weight: f32 = influences.iter().map(|(_, weight)| weight).sum();
+        let mut joint_indices = [0u16; 4];
+        let mut joint_weights = [0f32; 4];
+        for (slot, (joint_index, weight)) in influences.iter().enumerate() {
+            joint_indices[slot] = *joint_index;
+            joint_weights[slot] = if total_weight > 0. {
+                weight / total_weight
+            } else {
+                0.
+            };
+        }
+        per_vertex.push((joint_indices, joint_weights));
+    }
+    Some(per_vertex)
+}
+
+/// The setup-pose transform of `entity` (a [`SpineBone`] entity) relative to its [`Spine`]
+/// entity, composed by walking [`SpineBone::parent`] up to the root.
+///
+/// Valid only right as a skeleton becomes ready, before any animation has been applied and before
+/// these entities' own [`GlobalTransform`]s have been propagated for the first time.
+fn bone_bind_matrix(
+    entity: Entity,
+    bone_query: &Query<(&SpineBone, &Transform)>,
+    cache: &mut HashMap<Entity, Mat4>,
+) -> Mat4 {
+    if let Some(matrix) = cache.get(&entity) {
+        return *matrix;
+    }
+    let Ok((bone, transform)) = bone_query.get(entity) else {
+        return Mat4::IDENTITY;
+    };
+    let parent_matrix = match &bone.parent {
+        Some(parent) => bone_bind_matrix(parent.entity, bone_query, cache),
+        None => Mat4::IDENTITY,
+    };
+    let matrix = parent_matrix * transform.compute_matrix();
+    cache.insert(entity, matrix);
+    matrix
+}
@@ -0,0 +1,173 @@
+//! Deterministic snapshot/restore of a [`Spine`] rig, for rollback netcode (e.g. `bevy_ggrs`).
+
+use std::sync::atomic::Ordering;
+
+use bevy::prelude::*;
+use rusty_spine::BoneHandle;
+
+use crate::{Spine, SpineEventSuppressor};
+
+/// A serializable capture of a [`Spine`] rig's animation and pose state.
+///
+/// Taken with [`Spine::snapshot`] and applied with [`Spine::restore`]. Restoring a snapshot and
+/// then stepping the skeleton with the same, explicit `dt` reproduces the exact same pose, which
+/// is the property rollback netcode (e.g. `bevy_ggrs`) needs to resimulate frames.
+///
+/// Uses `Vec` rather than the `HashMap`s found elsewhere in the crate so that iteration order (and
+/// therefore any checksum taken of the snapshot) is stable.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpineSnapshot {
+    pub time: f32,
+    pub tracks: Vec<SpineTrackSnapshot>,
+    pub bones: Vec<SpineBoneSnapshot>,
+    pub slots: Vec<SpineSlotSnapshot>,
+}
+
+/// A single [`rusty_spine::animation_state::TrackEntry`]'s state, captured by [`SpineSnapshot`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpineTrackSnapshot {
+    pub track_index: usize,
+    pub animation_name: String,
+    pub track_time: f32,
+    pub timescale: f32,
+    pub alpha: f32,
+    pub mix_time: f32,
+    pub mix_duration: f32,
+    pub loop_: bool,
+    pub shortest_rotation: bool,
+}
+
+/// A single bone's local transform, captured by [`SpineSnapshot`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpineBoneSnapshot {
+    pub name: String,
+    pub x: f32,
+    pub y: f32,
+    pub rotation: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub shear_x: f32,
+    pub shear_y: f32,
+}
+
+/// A single slot's tint color, captured by [`SpineSnapshot`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpineSlotSnapshot {
+    pub name: String,
+    pub color: [f32; 4],
+}
+
+impl Spine {
+    /// Captures the current animation tracks, bone poses, and slot colors into a
+    /// [`SpineSnapshot`], suitable for checksumming and resimulation in rollback netcode.
+    ///
+    /// Does not capture anything not required to reproduce the rendered pose deterministically
+    /// (e.g. event listeners), since those are not expected to change across a rollback.
+    pub fn snapshot(&self) -> SpineSnapshot {
+        let mut tracks = vec![];
+        for track_index in 0..self.animation_state.tracks_count() {
+            if let Some(track) = self.animation_state.track_at_index(track_index) {
+                tracks.push(SpineTrackSnapshot {
+                    track_index,
+                    animation_name: track.animation().name().to_owned(),
+                    track_time: track.track_time(),
+                    timescale: track.timescale(),
+                    alpha: track.alpha(),
+                    mix_time: track.mix_time(),
+                    mix_duration: track.mix_duration(),
+                    loop_: track.loop_(),
+                    shortest_rotation: track.shortest_rotation(),
+                });
+            }
+        }
+        let mut bones = vec![];
+        for bone in self.skeleton.bones() {
+            bones.push(SpineBoneSnapshot {
+                name: bone.data().name().to_owned(),
+                x: bone.x(),
+                y: bone.y(),
+                rotation: bone.rotation(),
+                scale_x: bone.scale_x(),
+                scale_y: bone.scale_y(),
+                shear_x: bone.shear_x(),
+                shear_y: bone.shear_y(),
+            });
+        }
+        let mut slots = vec![];
+        for slot in self.skeleton.slots() {
+            let color = slot.color();
+            slots.push(SpineSlotSnapshot {
+                name: slot.data().name().to_owned(),
+                color: [color.r, color.g, color.b, color.a],
+            });
+        }
+        SpineSnapshot {
+            time: self.skeleton.time(),
+            tracks,
+            bones,
+            slots,
+        }
+    }
+
+    /// Restores a [`SpineSnapshot`] taken with [`Spine::snapshot`].
+    ///
+    /// Tracks are reconstructed with [`rusty_spine::AnimationState::set_animation_by_name`], which
+    /// would otherwise fire `Start`/`Interrupt`/etc. listener events as if the animation were
+    /// actually (re)starting. `suppressor` is raised for the duration of this call so those events
+    /// are dropped instead of being queued onto [`SpineEvent`](crate::SpineEvent), keeping
+    /// restoration side-effect-free for rollback netcode resimulating past frames.
+    /// Should be followed by a fixed-dt [`SkeletonController::update`](
+    /// `rusty_spine::controller::SkeletonController::update`) to bring the rig back in sync.
+    pub fn restore(&mut self, snapshot: &SpineSnapshot, suppressor: &SpineEventSuppressor) {
+        suppressor.0.store(true, Ordering::Relaxed);
+        self.skeleton.set_time(snapshot.time);
+        for track_snapshot in &snapshot.tracks {
+            let Some(mut track) = self.animation_state.set_animation_by_name(
+                track_snapshot.track_index,
+                &track_snapshot.animation_name,
+                track_snapshot.loop_,
+            ) else {
+                continue;
+            };
+            track.set_track_time(track_snapshot.track_time);
+            track.set_timescale(track_snapshot.timescale);
+            track.set_alpha(track_snapshot.alpha);
+            track.set_mix_time(track_snapshot.mix_time);
+            track.set_mix_duration(track_snapshot.mix_duration);
+            track.set_shortest_rotation(track_snapshot.shortest_rotation);
+        }
+        for bone_snapshot in &snapshot.bones {
+            let Some(handle) = self
+                .skeleton
+                .find_bone(&bone_snapshot.name)
+                .map(|bone| bone.handle())
+            else {
+                continue;
+            };
+            let handle: BoneHandle = handle;
+            if let Some(mut bone) = handle.get_mut(&mut self.skeleton) {
+                bone.set_x(bone_snapshot.x);
+                bone.set_y(bone_snapshot.y);
+                bone.set_rotation(bone_snapshot.rotation);
+                bone.set_scale_x(bone_snapshot.scale_x);
+                bone.set_scale_y(bone_snapshot.scale_y);
+                bone.set_shear_x(bone_snapshot.shear_x);
+                bone.set_shear_y(bone_snapshot.shear_y);
+            }
+        }
+        for slot_snapshot in &snapshot.slots {
+            if let Some(mut slot) = self.skeleton.find_slot_mut(&slot_snapshot.name) {
+                slot.color_mut().r = slot_snapshot.color[0];
+                slot.color_mut().g = slot_snapshot.color[1];
+                slot.color_mut().b = slot_snapshot.color[2];
+                slot.color_mut().a = slot_snapshot.color[3];
+            }
+        }
+        self.skeleton.update_world_transform();
+        suppressor.0.store(false, Ordering::Relaxed);
+    }
+}
@@ -4,12 +4,13 @@ use std::sync::{
 };
 
 use bevy::{
+    ecs::system::SystemState,
     prelude::*,
     render::{settings::WgpuSettings, RenderPlugin},
     winit::WinitPlugin,
 };
 
-use crate::{prelude::*, SpineSet};
+use crate::{ik, prelude::*, SpineEventQueue, SpineSet};
 
 pub fn test_app() -> App {
     let mut app = App::new();
@@ -74,3 +75,216 @@ fn spawn() {
     });
     app.update();
 }
+
+#[test]
+fn restore_does_not_requeue_events() {
+    let mut app = test_app_with_spineboy();
+
+    {
+        let mut query = app.world_mut().query::<&mut Spine>();
+        let mut spine = query.single_mut(app.world_mut());
+        let Spine(SkeletonController {
+            animation_state, ..
+        }) = spine.as_mut();
+        let _ = animation_state.set_animation_by_name(0, "walk", true);
+    }
+    assert!(
+        !app.world()
+            .resource::<SpineEventQueue>()
+            .0
+            .lock()
+            .unwrap()
+            .is_empty(),
+        "starting an animation should have queued a Start event"
+    );
+    app.world()
+        .resource::<SpineEventQueue>()
+        .0
+        .lock()
+        .unwrap()
+        .clear();
+    app.update();
+
+    let snapshot = {
+        let mut query = app.world_mut().query::<&Spine>();
+        query.single(app.world()).snapshot()
+    };
+    {
+        let suppressor = app.world().resource::<SpineEventSuppressor>().clone();
+        let mut query = app.world_mut().query::<&mut Spine>();
+        query
+            .single_mut(app.world_mut())
+            .restore(&snapshot, &suppressor);
+    }
+
+    assert!(
+        app.world()
+            .resource::<SpineEventQueue>()
+            .0
+            .lock()
+            .unwrap()
+            .is_empty(),
+        "restoring a snapshot must not requeue listener events"
+    );
+}
+
+#[test]
+fn ik_solve_fabrik_converges_on_reachable_target() {
+    let mut positions = vec![Vec3::ZERO, Vec3::new(1., 0., 0.), Vec3::new(2., 0., 0.)];
+    let segment_lengths = vec![1., 1.];
+    let target = Vec3::new(1., 1., 0.);
+
+    ik::solve_fabrik(&mut positions, &segment_lengths, target, 10);
+
+    assert!(
+        (positions[2] - target).length() < 0.01,
+        "tip should have converged on a reachable target, got {:?}",
+        positions[2]
+    );
+    assert!(((positions[1] - positions[0]).length() - 1.).abs() < 0.001);
+    assert!(((positions[2] - positions[1]).length() - 1.).abs() < 0.001);
+}
+
+#[test]
+fn ik_solve_fabrik_stretches_straight_for_unreachable_target() {
+    let mut positions = vec![Vec3::ZERO, Vec3::new(1., 0., 0.), Vec3::new(2., 0., 0.)];
+    let segment_lengths = vec![1., 1.];
+    let target = Vec3::new(0., 10., 0.);
+
+    ik::solve_fabrik(&mut positions, &segment_lengths, target, 10);
+
+    assert_eq!(positions[0], Vec3::ZERO);
+    assert!(positions[1].abs_diff_eq(Vec3::new(0., 1., 0.), 0.001));
+    assert!(positions[2].abs_diff_eq(Vec3::new(0., 2., 0.), 0.001));
+}
+
+#[test]
+fn ik_collect_chain_walks_parent_links_and_stops_at_root() {
+    let mut app = test_app_with_spineboy();
+
+    let bones: Vec<(Entity, Option<Entity>)> = {
+        let mut query = app.world_mut().query::<(Entity, &SpineBone)>();
+        query
+            .iter(app.world())
+            .map(|(entity, bone)| (entity, bone.parent.as_ref().map(|parent| parent.entity)))
+            .collect()
+    };
+    let (tip, parent) = *bones
+        .iter()
+        .find(|(_, parent)| parent.is_some())
+        .expect("spineboy should have at least one non-root bone");
+    let root = bones
+        .iter()
+        .find(|(_, parent)| parent.is_none())
+        .map(|(entity, _)| *entity)
+        .expect("spineboy should have a root bone");
+
+    let mut system_state: SystemState<Query<(&SpineBone, &mut Transform)>> =
+        SystemState::new(app.world_mut());
+    let bone_query = system_state.get_mut(app.world_mut());
+
+    let chain = ik::collect_chain(tip, 2, &bone_query).expect("chain of length 2 should exist");
+    assert_eq!(chain, vec![parent.unwrap(), tip]);
+
+    assert!(
+        ik::collect_chain(root, 2, &bone_query).is_none(),
+        "walking past the root bone's missing parent should fail instead of panicking"
+    );
+}
+
+#[test]
+fn crossfades_resolve_mix_duration_precedence() {
+    let mut crossfades = Crossfades::new();
+    crossfades.add("walk", "run", 0.1);
+    crossfades.add_from("walk", 0.2);
+    crossfades.add_to("run", 0.3);
+
+    // An exact pair wins over both wildcards.
+    assert_eq!(crossfades.resolve_mix_duration("walk", "run"), Some(0.1));
+    // A `from` wildcard wins over a `to` wildcard.
+    assert_eq!(crossfades.resolve_mix_duration("walk", "idle"), Some(0.2));
+    // A `to` wildcard applies when no exact pair or `from` wildcard matches.
+    assert_eq!(crossfades.resolve_mix_duration("idle", "run"), Some(0.3));
+    // Nothing matches, nothing is returned.
+    assert_eq!(crossfades.resolve_mix_duration("idle", "jump"), None);
+}
+
+#[test]
+fn geometry_set_bone_world_transform_round_trips_bone_global_matrix() {
+    let mut app = test_app_with_spineboy();
+
+    let spine_entity = {
+        let mut query = app.world_mut().query_filtered::<Entity, With<Spine>>();
+        query.single(app.world())
+    };
+    let spine_global_transform = *app.world().get::<GlobalTransform>(spine_entity).unwrap();
+
+    let target = {
+        let mut query = app.world_mut().query::<(Entity, &SpineBone)>();
+        query
+            .iter(app.world())
+            .find(|(_, bone)| bone.parent.is_some())
+            .map(|(entity, _)| entity)
+            .expect("spineboy should have at least one non-root bone")
+    };
+
+    let mut system_state: SystemState<Query<(&SpineBone, &Transform)>> =
+        SystemState::new(app.world_mut());
+    let bone_query = system_state.get(app.world());
+
+    let (bone, transform) = bone_query.get(target).unwrap();
+    let original_transform = *transform;
+    let world_matrix = bone_global_matrix(target, &bone_query, &spine_global_transform);
+
+    let mut restored_transform = Transform::IDENTITY;
+    set_bone_world_transform(
+        bone,
+        &mut restored_transform,
+        world_matrix,
+        &bone_query,
+        &spine_global_transform,
+    );
+
+    assert!(restored_transform
+        .translation
+        .abs_diff_eq(original_transform.translation, 0.001));
+    assert!(restored_transform
+        .rotation
+        .abs_diff_eq(original_transform.rotation, 0.001));
+}
+
+#[test]
+fn snapshot_restore_round_trips_bone_pose() {
+    let mut app = test_app_with_spineboy();
+
+    let snapshot = {
+        let mut query = app.world_mut().query::<&Spine>();
+        query.single(app.world()).snapshot()
+    };
+    let bone_name = snapshot.bones[0].name.clone();
+
+    {
+        let mut query = app.world_mut().query::<&mut Spine>();
+        let mut spine = query.single_mut(app.world_mut());
+        let handle = spine.skeleton.find_bone(&bone_name).unwrap().handle();
+        if let Some(mut bone) = handle.get_mut(&mut spine.skeleton) {
+            let x = bone.x();
+            bone.set_x(x + 1234.);
+        }
+    }
+
+    {
+        let suppressor = app.world().resource::<SpineEventSuppressor>().clone();
+        let mut query = app.world_mut().query::<&mut Spine>();
+        query
+            .single_mut(app.world_mut())
+            .restore(&snapshot, &suppressor);
+    }
+
+    let restored_x = {
+        let mut query = app.world_mut().query::<&Spine>();
+        let spine = query.single(app.world());
+        spine.skeleton.find_bone(&bone_name).unwrap().x()
+    };
+    assert_eq!(restored_x, snapshot.bones[0].x);
+}
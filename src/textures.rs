@@ -17,6 +17,12 @@ struct SpineTextureInternal {
     pub config: SpineTextureConfig,
 }
 
+/// Sampling settings read from an atlas page, forwarded in [`SpineTextureCreateEvent`].
+///
+/// Applied to the loaded [`Image`]'s sampler when adjusting Spine textures for rendering (see
+/// [`SpineSystem::AdjustSpineTextures`](`crate::SpineSystem::AdjustSpineTextures`)), so that
+/// nearest-filtered pixel-art atlases and repeating/mirrored wraps render the way they were
+/// authored.
 #[derive(Debug, Clone, Copy)]
 pub struct SpineTextureConfig {
     pub premultiplied_alpha: bool,
@@ -24,6 +30,12 @@ pub struct SpineTextureConfig {
     pub mag_filter: AtlasFilter,
     pub u_wrap: AtlasWrap,
     pub v_wrap: AtlasWrap,
+    /// Whether `min_filter`/`mag_filter` are one of the `MipMap*` variants, meaning the atlas was
+    /// exported expecting a full mip chain. When set, [`adjust_spine_textures`] generates one for
+    /// the loaded [`Image`] instead of just honoring the min/mag sampler.
+    ///
+    /// [`adjust_spine_textures`]: crate::adjust_spine_textures
+    pub generate_mipmaps: bool,
 }
 
 #[derive(Resource)]
@@ -73,6 +85,8 @@ impl SpineTextures {
                     mag_filter: page.mag_filter(),
                     u_wrap: page.u_wrap(),
                     v_wrap: page.v_wrap(),
+                    generate_mipmaps: is_mipmap_filter(page.min_filter())
+                        || is_mipmap_filter(page.mag_filter()),
                 },
             });
             page.renderer_object().set(SpineTexture(path.to_owned()));
@@ -101,17 +115,25 @@ impl SpineTextures {
     ) {
         let mut data = self.data.lock().unwrap();
         while let Some(texture) = data.remember.pop() {
-            let handle = asset_server.load(&texture.path);
             // if none, the atlas was already deleted before getting here
-            if let Some(atlas) = find_matching_atlas(atlases, texture.atlas_address) {
-                data.handles.push((texture.path.clone(), handle.clone()));
-                create_events.send(SpineTextureCreateEvent {
-                    path: texture.path,
-                    atlas,
-                    handle,
-                    config: texture.config,
-                });
-            }
+            let Some((atlas_handle, atlas)) = find_matching_atlas(atlases, texture.atlas_address)
+            else {
+                continue;
+            };
+            // Bind to the same `Handle<Image>` the `Atlas` asset already tracks as a dependency
+            // (see `Atlas::page_textures`), so this event and the `Atlas` agree on one strong
+            // handle per page and a re-exported page image re-uploads through both.
+            let handle = page_index_for_path(&atlas, &texture.path)
+                .and_then(|index| atlas.page_textures.get(index))
+                .cloned()
+                .unwrap_or_else(|| asset_server.load(&texture.path));
+            data.handles.push((texture.path.clone(), handle.clone()));
+            create_events.send(SpineTextureCreateEvent {
+                path: texture.path,
+                atlas: atlas_handle,
+                handle,
+                config: texture.config,
+            });
         }
         while let Some(texture_path) = data.forget.pop() {
             if let Some(index) = data.handles.iter().position(|i| i.0 == texture_path) {
@@ -125,11 +147,35 @@ impl SpineTextures {
     }
 }
 
-fn find_matching_atlas(atlases: &Assets<Atlas>, atlas_address: usize) -> Option<Handle<Atlas>> {
+/// Whether `filter` is one of the Spine `MipMap*` variants, i.e. the atlas page was exported
+/// expecting a full mip chain rather than a single sampled level.
+fn is_mipmap_filter(filter: AtlasFilter) -> bool {
+    matches!(
+        filter,
+        AtlasFilter::MipMap
+            | AtlasFilter::MipMapNearestNearest
+            | AtlasFilter::MipMapLinearNearest
+            | AtlasFilter::MipMapNearestLinear
+            | AtlasFilter::MipMapLinearLinear
+    )
+}
+
+fn find_matching_atlas(
+    atlases: &Assets<Atlas>,
+    atlas_address: usize,
+) -> Option<(Handle<Atlas>, &Atlas)> {
     for (atlas_handle, atlas) in atlases.iter() {
         if atlas.atlas.c_ptr() as usize == atlas_address {
-            return Some(atlases.get_handle(atlas_handle));
+            return Some((atlases.get_handle(atlas_handle), atlas));
         }
     }
     None
 }
+
+/// The index of `path`'s page within `atlas.atlas.pages()`, matching the order
+/// [`Atlas::page_textures`] was built in.
+fn page_index_for_path(atlas: &Atlas, path: &str) -> Option<usize> {
+    atlas.atlas.pages().position(|page| {
+        unsafe { page.renderer_object().get_unchecked::<SpineTexture>() }.0 == path
+    })
+}